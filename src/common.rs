@@ -1,19 +1,252 @@
-use std::{
-    fmt::{Debug, Display},
-    ops::*,
-};
-
-pub trait Numeric = Add<Output = Self>
-    + Sub<Output = Self>
-    + Mul<Output = Self>
-    + Div<Output = Self>
-    + AddAssign
-    + SubAssign
-    + MulAssign
-    + DivAssign
-    + PartialEq
-    + Sized
-    + Copy
-    + Debug
-    + Display
-    + Default;
+use crate::error::{Error, Result};
+use std::{cmp::Ordering, fmt::Debug, ops::*};
+
+/// A type which has an additive identity.
+pub trait Zero: Sized {
+    /// Returns the additive identity.
+    fn zero() -> Self;
+    /// Returns whether the value is the additive identity.
+    fn is_zero(&self) -> bool;
+}
+
+/// A type which has a multiplicative identity.
+pub trait One: Sized {
+    /// Returns the multiplicative identity.
+    fn one() -> Self;
+}
+
+/// The minimum required of a type for it to be used as the element of a
+/// Matrix. Operations on Matrices place further bounds on the element type
+/// through the traits below.
+pub trait Numeric = Copy + PartialEq + Debug + Zero;
+
+/// A Numeric type with addition and multiplication, which is all that is
+/// required for matrix products.
+pub trait Semiring =
+    Numeric + One + Add<Output = Self> + Mul<Output = Self> + AddAssign + MulAssign;
+
+/// A type with subtraction, which is closed for every type but the unsigned
+/// primitive integer types, where it overflows below zero.
+pub trait Subtraction: Sized + Sub<Output = Self> + SubAssign {
+    /// Whether the type has no negative values, so subtracting a larger value
+    /// from a smaller one overflows.
+    const UNSIGNED: bool;
+}
+
+/// A Semiring which also has subtraction.
+pub trait Ring = Semiring + Subtraction;
+
+/// A type with division, which is exact for every type but the primitive
+/// integer types, whose division truncates.
+pub trait Division: Sized + Div<Output = Self> + DivAssign {
+    /// Whether division rounds the quotient towards zero instead of being
+    /// exact, so operations that need exact division can detect it.
+    const TRUNCATES: bool;
+}
+
+/// A Ring which also has division, as required by decompositions and solvers.
+/// Integer types satisfy this bound with truncating division, so operations
+/// that need exact division document how they handle them.
+pub trait Field = Ring + Division;
+
+/// A Field with an exact remainder and a total order, such as the primitive
+/// integer types, which can be used as the numerator and denominator of a
+/// `Ratio`.
+pub trait Integer = Field + Ord + Rem<Output = Self>;
+
+/// A Field which represents a real number, as required by algorithms which need
+/// square roots, norms or a tolerance for rounding error.
+pub trait Real: Field + PartialOrd {
+    /// The difference between one and the next largest representable number.
+    const EPSILON: Self;
+
+    /// Returns the absolute value of the number.
+    fn abs(self) -> Self;
+    /// Returns the square root of the number.
+    fn sqrt(self) -> Self;
+    /// Returns the natural logarithm of the number.
+    fn ln(self) -> Self;
+    /// Converts a usize to the nearest representable number.
+    fn from_usize(n: usize) -> Self;
+}
+
+macro_rules! impl_identities {
+    ($zero:expr, $one:expr, $($t:ty),*) => {$(
+        impl Zero for $t {
+            fn zero() -> Self { $zero }
+            fn is_zero(&self) -> bool { *self == $zero }
+        }
+
+        impl One for $t {
+            fn one() -> Self { $one }
+        }
+    )*};
+}
+
+impl_identities!(0, 1, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_identities!(0.0, 1.0, f32, f64);
+
+macro_rules! impl_subtraction {
+    ($unsigned:expr, $($t:ty),*) => {$(
+        impl Subtraction for $t {
+            const UNSIGNED: bool = $unsigned;
+        }
+    )*};
+}
+
+impl_subtraction!(false, i8, i16, i32, i64, i128, isize, f32, f64);
+impl_subtraction!(true, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_division {
+    ($truncates:expr, $($t:ty),*) => {$(
+        impl Division for $t {
+            const TRUNCATES: bool = $truncates;
+        }
+    )*};
+}
+
+impl_division!(true, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_division!(false, f32, f64);
+
+macro_rules! impl_real {
+    ($($t:ty),*) => {$(
+        impl Real for $t {
+            const EPSILON: Self = <$t>::EPSILON;
+
+            fn abs(self) -> Self { <$t>::abs(self) }
+            fn sqrt(self) -> Self { <$t>::sqrt(self) }
+            fn ln(self) -> Self { <$t>::ln(self) }
+            fn from_usize(n: usize) -> Self { n as $t }
+        }
+    )*};
+}
+
+impl_real!(f32, f64);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// An ordered Field element stored as a non-negative magnitude and a sign, so
+/// that arithmetic on it only ever subtracts a smaller magnitude from a larger
+/// one. Exact elimination uses it to avoid overflowing unsigned element types
+/// on intermediate values which are negative.
+pub(crate) struct SignMagnitude<T> {
+    magnitude: T,
+    negative: bool,
+}
+
+impl<T: Field + PartialOrd> SignMagnitude<T> {
+    pub(crate) fn new(value: T) -> Self {
+        if value < T::zero() {
+            Self::with_sign(T::zero() - value, true)
+        } else {
+            Self::with_sign(value, false)
+        }
+    }
+
+    // Zero is always stored as non-negative, so the derived equality holds.
+    fn with_sign(magnitude: T, negative: bool) -> Self {
+        Self {
+            magnitude,
+            negative: negative && !magnitude.is_zero(),
+        }
+    }
+
+    /// Converts back to the element type, returning `Error::NotRepresentable`
+    /// if the value is negative and the element type is unsigned.
+    pub(crate) fn value(self) -> Result<T> {
+        match (self.negative, T::UNSIGNED) {
+            (false, _) => Ok(self.magnitude),
+            (true, false) => Ok(T::zero() - self.magnitude),
+            (true, true) => Err(Error::NotRepresentable),
+        }
+    }
+}
+
+impl<T: Field + PartialOrd> Zero for SignMagnitude<T> {
+    fn zero() -> Self { Self::with_sign(T::zero(), false) }
+    fn is_zero(&self) -> bool { self.magnitude.is_zero() }
+}
+
+impl<T: Field + PartialOrd> One for SignMagnitude<T> {
+    fn one() -> Self { Self::with_sign(T::one(), false) }
+}
+
+impl<T: Field + PartialOrd> PartialOrd for SignMagnitude<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.partial_cmp(&other.magnitude),
+            (true, true) => other.magnitude.partial_cmp(&self.magnitude),
+            (true, false) => Some(Ordering::Less),
+            (false, true) => Some(Ordering::Greater),
+        }
+    }
+}
+
+impl<T: Field + PartialOrd> Add for SignMagnitude<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.negative == rhs.negative {
+            Self::with_sign(self.magnitude + rhs.magnitude, self.negative)
+        } else if self.magnitude >= rhs.magnitude {
+            Self::with_sign(self.magnitude - rhs.magnitude, self.negative)
+        } else {
+            Self::with_sign(rhs.magnitude - self.magnitude, rhs.negative)
+        }
+    }
+}
+
+impl<T: Field + PartialOrd> Sub for SignMagnitude<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + Self::with_sign(rhs.magnitude, !rhs.negative)
+    }
+}
+
+impl<T: Field + PartialOrd> Mul for SignMagnitude<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::with_sign(
+            self.magnitude * rhs.magnitude,
+            self.negative != rhs.negative,
+        )
+    }
+}
+
+impl<T: Field + PartialOrd> Div for SignMagnitude<T> {
+    type Output = Self;
+
+    // Truncating division rounds towards zero, so dividing the magnitudes
+    // rounds the same way as dividing the values would.
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::with_sign(
+            self.magnitude / rhs.magnitude,
+            self.negative != rhs.negative,
+        )
+    }
+}
+
+impl<T: Field + PartialOrd> AddAssign for SignMagnitude<T> {
+    fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+}
+
+impl<T: Field + PartialOrd> SubAssign for SignMagnitude<T> {
+    fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+}
+
+impl<T: Field + PartialOrd> MulAssign for SignMagnitude<T> {
+    fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+}
+
+impl<T: Field + PartialOrd> DivAssign for SignMagnitude<T> {
+    fn div_assign(&mut self, rhs: Self) { *self = *self / rhs; }
+}
+
+impl<T: Field + PartialOrd> Subtraction for SignMagnitude<T> {
+    const UNSIGNED: bool = false;
+}
+
+impl<T: Field + PartialOrd> Division for SignMagnitude<T> {
+    const TRUNCATES: bool = T::TRUNCATES;
+}
@@ -0,0 +1,126 @@
+use crate::{
+    common::Real,
+    error::{Error, Result},
+    implementations::HeapMatrix,
+    matrix::*,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+/// The Cholesky decomposition of a symmetric positive-definite Matrix, such
+/// that `A = L * L^T` where `L` is lower triangular with a positive diagonal.
+pub struct CholeskyDecomposition<T: Real> {
+    l: HeapMatrix<T>,
+}
+
+impl<T: Real> CholeskyDecomposition<T> {
+    /// Decomposes the given Matrix, returning `Error::NotSquare` if it is not
+    /// square or `Error::NotPositiveDefinite` if it is not positive-definite.
+    /// Only the lower triangle of the Matrix is read, so it is assumed to be
+    /// symmetric.
+    pub fn new<'a>(mat: &impl MatrixRef<'a, T>) -> Result<Self> {
+        check_square(mat)?;
+
+        let n = mat.get_x_len();
+        let mut l = vec![T::zero(); n * n];
+
+        for j in 0..n {
+            let mut diagonal = mat.get_at_unchecked(j, j);
+            for k in 0..j {
+                diagonal -= l[j * n + k] * l[j * n + k];
+            }
+
+            if diagonal <= T::zero() {
+                return Err(Error::NotPositiveDefinite);
+            }
+
+            let diagonal = diagonal.sqrt();
+            l[j * n + j] = diagonal;
+
+            for i in j + 1..n {
+                let mut cell = mat.get_at_unchecked(j, i);
+                for k in 0..j {
+                    cell -= l[i * n + k] * l[j * n + k];
+                }
+                l[i * n + j] = cell / diagonal;
+            }
+        }
+
+        Ok(Self {
+            l: HeapMatrix::new(&l, n, n),
+        })
+    }
+
+    /// Gets the lower triangular factor `L`.
+    pub fn l(&self) -> &HeapMatrix<T> { &self.l }
+
+    /// Returns the determinant of the decomposed Matrix.
+    pub fn determinant(&self) -> T {
+        let n = self.l.x_len;
+        let product =
+            (0..n).fold(T::one(), |product, i| product * self.l.data[i * n + i]);
+
+        product * product
+    }
+
+    /// Returns the natural logarithm of the determinant of the decomposed
+    /// Matrix, which avoids the overflow that `determinant` is prone to for
+    /// large Matrices.
+    pub fn log_determinant(&self) -> T {
+        let n = self.l.x_len;
+        let sum = (0..n).fold(T::zero(), |sum, i| sum + self.l.data[i * n + i].ln());
+
+        sum + sum
+    }
+
+    /// Solves `A * x = b` for `x`, returning `Error::IncorrectLength` if `b`
+    /// does not have the same length as `A` has rows.
+    pub fn solve_vec(&self, b: &[T]) -> Result<Vec<T>> {
+        let n = self.l.x_len;
+        if b.len() != n {
+            return Err(Error::IncorrectLength {
+                expected: n,
+                found: b.len(),
+            });
+        }
+
+        let l = &self.l.data;
+        let mut x = b.to_vec();
+
+        for i in 0..n {
+            for j in 0..i {
+                let cell = l[i * n + j] * x[j];
+                x[i] -= cell;
+            }
+            x[i] /= l[i * n + i];
+        }
+
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                let cell = l[j * n + i] * x[j];
+                x[i] -= cell;
+            }
+            x[i] /= l[i * n + i];
+        }
+
+        Ok(x)
+    }
+
+    /// Solves `A * X = B` for `X`, treating each column of `B` as a separate
+    /// right-hand side. Returns `Error::DimensionMismatch` if `B` does not have
+    /// the same amount of rows as `A`.
+    pub fn solve<'a>(&self, b: &impl MatrixRef<'a, T>) -> Result<HeapMatrix<T>> {
+        map_columns(self.l.x_len, b, |column| self.solve_vec(column))
+    }
+
+    /// Returns the inverse of the decomposed Matrix.
+    pub fn inverse(&self) -> HeapMatrix<T> {
+        let n = self.l.x_len;
+        let mut identity = vec![T::zero(); n * n];
+        for i in 0..n {
+            identity[i * n + i] = T::one();
+        }
+
+        self.solve(&HeapMatrix::new(&identity, n, n))
+            .expect("identity has the same dimensions as the decomposed matrix")
+    }
+}
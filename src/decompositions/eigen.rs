@@ -0,0 +1,405 @@
+use crate::{
+    common::{Real, Zero},
+    error::{Error, Result},
+    implementations::HeapMatrix,
+    matrix::*,
+    numbers::Complex,
+};
+
+/// The amount of QR iterations allowed for each eigenvalue before giving up.
+pub const MAX_QR_ITERATIONS: usize = 30;
+
+#[derive(Debug, Clone, PartialEq)]
+/// The eigenvalues of a general real square Matrix, which are computed by
+/// reducing the Matrix to upper Hessenberg form and applying the Francis
+/// double-shift QR algorithm.
+///
+/// Complex eigenvalues always appear in conjugate pairs.
+pub struct Eigen<T: Real> {
+    mat: HeapMatrix<T>,
+    eigenvalues: Vec<Complex<T>>,
+}
+
+impl<T: Real> Eigen<T> {
+    /// Computes the eigenvalues of the given Matrix, returning
+    /// `Error::NotSquare` if it is not square or `Error::NoConvergence` if an
+    /// eigenvalue could not be found within `MAX_QR_ITERATIONS` iterations.
+    pub fn new<'a>(mat: &impl MatrixRef<'a, T>) -> Result<Self> {
+        check_square(mat)?;
+
+        let n = mat.get_x_len();
+        let mut a = mat.to_vec();
+        reduce_to_hessenberg(&mut a, n);
+
+        let mut eigenvalues = hessenberg_qr(&mut a, n)?;
+        eigenvalues.sort_by(|x, y| {
+            y.re.partial_cmp(&x.re)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(y.im.partial_cmp(&x.im).unwrap_or(std::cmp::Ordering::Equal))
+        });
+
+        Ok(Self {
+            mat: HeapMatrix::new(&mat.to_vec(), n, n),
+            eigenvalues,
+        })
+    }
+
+    /// Gets the eigenvalues, sorted by descending real part and then by
+    /// descending imaginary part.
+    pub fn eigenvalues(&self) -> &[Complex<T>] { &self.eigenvalues }
+
+    /// Computes an eigenvector with a 2-norm of one for each eigenvalue using
+    /// inverse iteration, returning them as the columns of a Matrix where
+    /// column `i` corresponds to `eigenvalues()[i]`.
+    pub fn eigenvectors(&self) -> HeapMatrix<Complex<T>> {
+        let n = self.mat.x_len;
+        let scale = self.mat.data.iter().fold(T::zero(), |max, x| {
+            if x.abs() > max {
+                x.abs()
+            } else {
+                max
+            }
+        });
+        // The shift is perturbed slightly so that `A - shift * I` is not
+        // exactly singular.
+        let perturbation = if scale == T::zero() { T::one() } else { scale } * T::EPSILON;
+
+        let mut vectors = vec![Complex::zero(); n * n];
+        for (column, eigenvalue) in self.eigenvalues.iter().enumerate() {
+            let shift = Complex::new(eigenvalue.re + perturbation, eigenvalue.im);
+
+            let mut shifted: Vec<Complex<T>> =
+                self.mat.data.iter().map(|x| Complex::from(*x)).collect();
+            for i in 0..n {
+                shifted[i * n + i] -= shift;
+            }
+            let lu = ComplexLu::new(shifted, n, perturbation);
+
+            let mut vector = vec![Complex::from(T::one()); n];
+            for _ in 0..3 {
+                vector = lu.solve(vector);
+
+                let norm = vector
+                    .iter()
+                    .fold(T::zero(), |sum, x| sum + x.norm_sqr())
+                    .sqrt();
+                for x in vector.iter_mut() {
+                    *x /= Complex::from(norm);
+                }
+            }
+
+            for (y, x) in vector.into_iter().enumerate() {
+                vectors[y * n + column] = x;
+            }
+        }
+
+        HeapMatrix::new(&vectors, n, n)
+    }
+}
+
+/// Reduces the `n x n` row-major `a` to upper Hessenberg form in place using
+/// Gaussian elimination with pivoting, which preserves its eigenvalues.
+fn reduce_to_hessenberg<T: Real>(a: &mut [T], n: usize) {
+    for m in 1..n.saturating_sub(1) {
+        let mut x = T::zero();
+        let mut pivot = m;
+        for j in m..n {
+            if a[j * n + m - 1].abs() > x.abs() {
+                x = a[j * n + m - 1];
+                pivot = j;
+            }
+        }
+
+        if pivot != m {
+            for j in m - 1..n {
+                a.swap(pivot * n + j, m * n + j);
+            }
+            for j in 0..n {
+                a.swap(j * n + pivot, j * n + m);
+            }
+        }
+
+        if x == T::zero() {
+            continue;
+        }
+
+        for i in m + 1..n {
+            let y = a[i * n + m - 1] / x;
+            if y == T::zero() {
+                continue;
+            }
+
+            a[i * n + m - 1] = T::zero();
+            for j in m..n {
+                let cell = y * a[m * n + j];
+                a[i * n + j] -= cell;
+            }
+            for j in 0..n {
+                let cell = y * a[j * n + i];
+                a[j * n + m] += cell;
+            }
+        }
+    }
+}
+
+/// Finds the eigenvalues of the `n x n` row-major upper Hessenberg `h` with the
+/// Francis double-shift QR algorithm, destroying `h` in the process.
+fn hessenberg_qr<T: Real>(h: &mut [T], n: usize) -> Result<Vec<Complex<T>>> {
+    let zero = T::zero();
+    let half = T::one() / (T::one() + T::one());
+    let sign = |a: T, b: T| if b >= zero { a.abs() } else { zero - a.abs() };
+    // Indices are one-based to follow the classic formulation of the
+    // algorithm.
+    let at = |i: usize, j: usize| (i - 1) * n + j - 1;
+
+    let mut norm = zero;
+    for i in 1..=n {
+        for j in i.saturating_sub(1).max(1)..=n {
+            norm += h[at(i, j)].abs();
+        }
+    }
+
+    let mut eigenvalues = Vec::with_capacity(n);
+    let mut nn = n;
+    let mut t = zero;
+
+    while nn >= 1 {
+        let mut iterations = 0;
+
+        loop {
+            // Look for a single small subdiagonal element to split the
+            // Matrix at.
+            let mut l = nn;
+            while l >= 2 {
+                let mut s = h[at(l - 1, l - 1)].abs() + h[at(l, l)].abs();
+                if s == zero {
+                    s = norm;
+                }
+                if h[at(l, l - 1)].abs() + s == s {
+                    h[at(l, l - 1)] = zero;
+                    break;
+                }
+                l -= 1;
+            }
+
+            let mut x = h[at(nn, nn)];
+            if l == nn {
+                // One root found.
+                eigenvalues.push(Complex::from(x + t));
+                nn -= 1;
+                break;
+            }
+
+            let mut y = h[at(nn - 1, nn - 1)];
+            let mut w = h[at(nn, nn - 1)] * h[at(nn - 1, nn)];
+            if l == nn - 1 {
+                // Two roots found.
+                let p = half * (y - x);
+                let q = p * p + w;
+                let z = q.abs().sqrt();
+                x += t;
+
+                if q >= zero {
+                    let z = p + sign(z, p);
+                    let first = x + z;
+                    let second = if z != zero { x - w / z } else { first };
+                    eigenvalues.push(Complex::from(first));
+                    eigenvalues.push(Complex::from(second));
+                } else {
+                    eigenvalues.push(Complex::new(x + p, z));
+                    eigenvalues.push(Complex::new(x + p, zero - z));
+                }
+
+                nn = nn.saturating_sub(2);
+                break;
+            }
+
+            if iterations == MAX_QR_ITERATIONS {
+                return Err(Error::NoConvergence { iterations });
+            }
+
+            if iterations == 10 || iterations == 20 {
+                // Exceptional shift.
+                t += x;
+                for i in 1..=nn {
+                    h[at(i, i)] -= x;
+                }
+                let s = h[at(nn, nn - 1)].abs() + h[at(nn - 1, nn - 2)].abs();
+                x = T::from_usize(3) * s / T::from_usize(4);
+                y = x;
+                w = zero - T::from_usize(7) * s * s / T::from_usize(16);
+            }
+            iterations += 1;
+
+            // Look for two consecutive small subdiagonal elements.
+            let mut m = nn - 2;
+            let (mut p, mut q, mut r);
+            loop {
+                let z = h[at(m, m)];
+                let rr = x - z;
+                let ss = y - z;
+                p = (rr * ss - w) / h[at(m + 1, m)] + h[at(m, m + 1)];
+                q = h[at(m + 1, m + 1)] - z - rr - ss;
+                r = h[at(m + 2, m + 1)];
+                let s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+
+                if m == l {
+                    break;
+                }
+
+                let u = h[at(m, m - 1)].abs() * (q.abs() + r.abs());
+                let v = p.abs()
+                    * (h[at(m - 1, m - 1)].abs() + z.abs() + h[at(m + 1, m + 1)].abs());
+                if u + v == v {
+                    break;
+                }
+                m -= 1;
+            }
+
+            for i in m + 2..=nn {
+                h[at(i, i - 2)] = zero;
+                if i != m + 2 {
+                    h[at(i, i - 3)] = zero;
+                }
+            }
+
+            // Double QR step on rows l to nn and columns m to nn.
+            for k in m..nn {
+                if k != m {
+                    p = h[at(k, k - 1)];
+                    q = h[at(k + 1, k - 1)];
+                    r = if k != nn - 1 {
+                        h[at(k + 2, k - 1)]
+                    } else {
+                        zero
+                    };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x != zero {
+                        p /= x;
+                        q /= x;
+                        r /= x;
+                    }
+                }
+
+                let s = sign((p * p + q * q + r * r).sqrt(), p);
+                if s == zero {
+                    continue;
+                }
+
+                if k == m {
+                    if l != m {
+                        h[at(k, k - 1)] = zero - h[at(k, k - 1)];
+                    }
+                } else {
+                    h[at(k, k - 1)] = zero - s * x;
+                }
+
+                p += s;
+                x = p / s;
+                y = q / s;
+                let z = r / s;
+                q /= p;
+                r /= p;
+
+                for j in k..=nn {
+                    let mut p = h[at(k, j)] + q * h[at(k + 1, j)];
+                    if k != nn - 1 {
+                        p += r * h[at(k + 2, j)];
+                        h[at(k + 2, j)] -= p * z;
+                    }
+                    h[at(k + 1, j)] -= p * y;
+                    h[at(k, j)] -= p * x;
+                }
+
+                for i in l..=nn.min(k + 3) {
+                    let mut p = x * h[at(i, k)] + y * h[at(i, k + 1)];
+                    if k != nn - 1 {
+                        p += z * h[at(i, k + 2)];
+                        h[at(i, k + 2)] -= p * r;
+                    }
+                    h[at(i, k + 1)] -= p * q;
+                    h[at(i, k)] -= p;
+                }
+            }
+        }
+    }
+
+    Ok(eigenvalues)
+}
+
+/// An LU decomposition with partial pivoting over complex numbers, used for
+/// inverse iteration. Zero pivots are replaced with `tiny` so that nearly
+/// singular Matrices can still be solved.
+struct ComplexLu<T: Real> {
+    data: Vec<Complex<T>>,
+    permutation: Vec<usize>,
+    n: usize,
+}
+
+impl<T: Real> ComplexLu<T> {
+    fn new(mut data: Vec<Complex<T>>, n: usize, tiny: T) -> Self {
+        let mut permutation: Vec<usize> = (0..n).collect();
+
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|i, j| {
+                    data[i * n + k]
+                        .norm_sqr()
+                        .partial_cmp(&data[j * n + k].norm_sqr())
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .unwrap_or(k);
+
+            if pivot != k {
+                swap_rows(&mut data, n, pivot, k);
+                permutation.swap(pivot, k);
+            }
+
+            if data[k * n + k] == Complex::zero() {
+                data[k * n + k] = Complex::from(tiny);
+            }
+
+            let pivot = data[k * n + k];
+            for i in k + 1..n {
+                data[i * n + k] /= pivot;
+
+                let factor = data[i * n + k];
+                for j in k + 1..n {
+                    let cell = factor * data[k * n + j];
+                    data[i * n + j] -= cell;
+                }
+            }
+        }
+
+        Self {
+            data,
+            permutation,
+            n,
+        }
+    }
+
+    fn solve(&self, b: Vec<Complex<T>>) -> Vec<Complex<T>> {
+        let n = self.n;
+        let mut x: Vec<Complex<T>> = self.permutation.iter().map(|i| b[*i]).collect();
+
+        for i in 0..n {
+            for j in 0..i {
+                let cell = self.data[i * n + j] * x[j];
+                x[i] -= cell;
+            }
+        }
+
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                let cell = self.data[i * n + j] * x[j];
+                x[i] -= cell;
+            }
+            x[i] /= self.data[i * n + i];
+        }
+
+        x
+    }
+}
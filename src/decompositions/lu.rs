@@ -0,0 +1,159 @@
+use crate::{
+    common::{Field, Numeric},
+    error::{Error, Result},
+    implementations::HeapMatrix,
+    matrix::*,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+/// The LU decomposition of a square Matrix with partial pivoting, such that
+/// `P * A = L * U` where `P` is a row permutation, `L` is unit lower triangular
+/// and `U` is upper triangular.
+///
+/// The decomposition is intended for element types where division is exact,
+/// such as floating-point numbers.
+pub struct LuDecomposition<T: Numeric> {
+    factors: HeapMatrix<T>,
+    permutation: Vec<usize>,
+    negate: bool,
+    singular: bool,
+}
+
+impl<T: Field + PartialOrd> LuDecomposition<T> {
+    /// Decomposes the given Matrix, returning `Error::NotSquare` if it is not
+    /// square. Singular Matrices can still be decomposed, but cannot be used to
+    /// solve linear systems.
+    pub fn new<'a>(mat: &impl MatrixRef<'a, T>) -> Result<Self> {
+        check_square(mat)?;
+
+        let n = mat.get_x_len();
+        let mut data = mat.to_vec();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut negate = false;
+        let mut singular = false;
+
+        for k in 0..n {
+            let pivot = match find_pivot(&data, n, k, k, n) {
+                Some(pivot) => pivot,
+                None => {
+                    singular = true;
+                    continue;
+                }
+            };
+
+            if pivot != k {
+                swap_rows(&mut data, n, pivot, k);
+                permutation.swap(pivot, k);
+                negate = !negate;
+            }
+
+            let pivot = data[k * n + k];
+            for i in k + 1..n {
+                data[i * n + k] /= pivot;
+
+                let factor = data[i * n + k];
+                for j in k + 1..n {
+                    let cell = factor * data[k * n + j];
+                    data[i * n + j] -= cell;
+                }
+            }
+        }
+
+        Ok(Self {
+            factors: HeapMatrix::new(&data, n, n),
+            permutation,
+            negate,
+            singular,
+        })
+    }
+
+    /// Gets the L and U factors packed into a single Matrix. The elements below
+    /// the main diagonal belong to L, whose unit diagonal is not stored, and
+    /// the remaining elements belong to U.
+    pub fn factors(&self) -> &HeapMatrix<T> { &self.factors }
+
+    /// Gets the row permutation, where the row at index `i` of `P * A` is the
+    /// row at index `permutation()[i]` of `A`.
+    pub fn permutation(&self) -> &[usize] { &self.permutation }
+
+    /// Returns whether the decomposed Matrix is singular.
+    pub fn is_singular(&self) -> bool { self.singular }
+
+    /// Returns the determinant of the decomposed Matrix.
+    pub fn determinant(&self) -> T {
+        if self.singular {
+            return T::zero();
+        }
+
+        let n = self.factors.x_len;
+        let det = (1..n).fold(self.factors.data[0], |det, i| {
+            det * self.factors.data[i * n + i]
+        });
+
+        if self.negate {
+            T::zero() - det
+        } else {
+            det
+        }
+    }
+
+    /// Solves `A * x = b` for `x`, returning `Error::IncorrectLength` if `b`
+    /// does not have the same length as `A` has rows.
+    pub fn solve_vec(&self, b: &[T]) -> Result<Vec<T>> {
+        let n = self.factors.x_len;
+        if b.len() != n {
+            return Err(Error::IncorrectLength {
+                expected: n,
+                found: b.len(),
+            });
+        }
+
+        if self.singular {
+            return Err(Error::Singular);
+        }
+
+        let mut x: Vec<T> = self.permutation.iter().map(|i| b[*i]).collect();
+        let lu = &self.factors.data;
+
+        for i in 0..n {
+            for j in 0..i {
+                let cell = lu[i * n + j] * x[j];
+                x[i] -= cell;
+            }
+        }
+
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                let cell = lu[i * n + j] * x[j];
+                x[i] -= cell;
+            }
+            x[i] /= lu[i * n + i];
+        }
+
+        Ok(x)
+    }
+
+    /// Solves `A * X = B` for `X`, treating each column of `B` as a separate
+    /// right-hand side. Returns `Error::DimensionMismatch` if `B` does not have
+    /// the same amount of rows as `A`.
+    pub fn solve<'a>(&self, b: &impl MatrixRef<'a, T>) -> Result<HeapMatrix<T>> {
+        map_columns(self.factors.x_len, b, |column| self.solve_vec(column))
+    }
+
+    /// Returns the inverse of the decomposed Matrix, or `Error::Singular` if it
+    /// has no inverse.
+    pub fn inverse(&self) -> Result<HeapMatrix<T>> {
+        if self.singular {
+            return Err(Error::Singular);
+        }
+
+        let n = self.factors.x_len;
+        let mut identity = vec![T::zero(); n * n];
+        let one = T::one();
+        for i in 0..n {
+            identity[i * n + i] = one;
+        }
+
+        self.solve(&HeapMatrix::new(&identity, n, n))
+    }
+}
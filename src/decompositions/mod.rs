@@ -0,0 +1,13 @@
+pub mod cholesky;
+pub mod eigen;
+pub mod lu;
+pub mod qr;
+pub mod svd;
+pub mod symmetric_eigen;
+
+pub use cholesky::*;
+pub use eigen::*;
+pub use lu::*;
+pub use qr::*;
+pub use svd::*;
+pub use symmetric_eigen::*;
@@ -0,0 +1,213 @@
+use crate::{
+    common::Real,
+    error::{Error, Result},
+    implementations::HeapMatrix,
+    matrix::*,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+/// The QR decomposition of an `m x n` Matrix computed with Householder
+/// reflections, such that `A = Q * R` where `Q` is orthogonal and `R` is upper
+/// triangular.
+///
+/// The reflectors are stored in compact form, so `Q` is only formed when it is
+/// requested.
+pub struct QrDecomposition<T: Real> {
+    /// R is stored on and above the main diagonal, while the Householder
+    /// vectors are stored below it with their implicit leading one omitted.
+    factors: HeapMatrix<T>,
+    tau: Vec<T>,
+}
+
+impl<T: Real> QrDecomposition<T> {
+    /// Decomposes the given Matrix.
+    pub fn new<'a>(mat: &impl MatrixRef<'a, T>) -> Self {
+        let columns = mat.get_x_len();
+        let rows = mat.get_y_len();
+        let mut data = mat.to_vec();
+        let mut tau = Vec::with_capacity(rows.min(columns));
+
+        for k in 0..rows.min(columns) {
+            let mut norm = T::zero();
+            for i in k..rows {
+                norm += data[i * columns + k] * data[i * columns + k];
+            }
+            let norm = norm.sqrt();
+
+            if norm == T::zero() {
+                tau.push(T::zero());
+                continue;
+            }
+
+            let head = data[k * columns + k];
+            let beta = if head < T::zero() {
+                norm
+            } else {
+                T::zero() - norm
+            };
+            let scale = head - beta;
+
+            for i in k + 1..rows {
+                data[i * columns + k] /= scale;
+            }
+            data[k * columns + k] = beta;
+
+            let t = (beta - head) / beta;
+            for j in k + 1..columns {
+                let mut s = data[k * columns + j];
+                for i in k + 1..rows {
+                    s += data[i * columns + k] * data[i * columns + j];
+                }
+                s *= t;
+
+                data[k * columns + j] -= s;
+                for i in k + 1..rows {
+                    let cell = s * data[i * columns + k];
+                    data[i * columns + j] -= cell;
+                }
+            }
+
+            tau.push(t);
+        }
+
+        Self {
+            factors: HeapMatrix::new(&data, columns, rows),
+            tau,
+        }
+    }
+
+    /// Applies `Q^T` to the vector `b` in place.
+    fn apply_q_transpose(&self, b: &mut [T]) {
+        let columns = self.factors.x_len;
+        let rows = self.factors.y_len;
+        let data = &self.factors.data;
+
+        for (k, t) in self.tau.iter().enumerate() {
+            let mut s = b[k];
+            for i in k + 1..rows {
+                s += data[i * columns + k] * b[i];
+            }
+            s *= *t;
+
+            b[k] -= s;
+            for i in k + 1..rows {
+                b[i] -= s * data[i * columns + k];
+            }
+        }
+    }
+
+    /// Forms the first `width` columns of `Q`.
+    fn form_q(&self, width: usize) -> HeapMatrix<T> {
+        let columns = self.factors.x_len;
+        let rows = self.factors.y_len;
+        let data = &self.factors.data;
+
+        let mut q = vec![T::zero(); rows * width];
+        for i in 0..width {
+            q[i * width + i] = T::one();
+        }
+
+        for (k, t) in self.tau.iter().enumerate().rev() {
+            for j in 0..width {
+                let mut s = q[k * width + j];
+                for i in k + 1..rows {
+                    s += data[i * columns + k] * q[i * width + j];
+                }
+                s *= *t;
+
+                q[k * width + j] -= s;
+                for i in k + 1..rows {
+                    q[i * width + j] -= s * data[i * columns + k];
+                }
+            }
+        }
+
+        HeapMatrix::new(&q, width, rows)
+    }
+
+    /// Forms the first `height` rows of `R`.
+    fn form_r(&self, height: usize) -> HeapMatrix<T> {
+        let columns = self.factors.x_len;
+        let mut r = vec![T::zero(); height * columns];
+
+        for i in 0..height.min(self.factors.y_len) {
+            for j in i..columns {
+                r[i * columns + j] = self.factors.data[i * columns + j];
+            }
+        }
+
+        HeapMatrix::new(&r, columns, height)
+    }
+
+    /// Returns the full `m x m` orthogonal factor `Q`.
+    pub fn q(&self) -> HeapMatrix<T> { self.form_q(self.factors.y_len) }
+
+    /// Returns the full `m x n` upper triangular factor `R`.
+    pub fn r(&self) -> HeapMatrix<T> { self.form_r(self.factors.y_len) }
+
+    /// Returns the thin `m x k` factor `Q`, where `k = min(m, n)`.
+    pub fn thin_q(&self) -> HeapMatrix<T> { self.form_q(self.tau.len()) }
+
+    /// Returns the thin `k x n` factor `R`, where `k = min(m, n)`.
+    pub fn thin_r(&self) -> HeapMatrix<T> { self.form_r(self.tau.len()) }
+
+    /// Returns the numerical rank of the decomposed Matrix, estimated from the
+    /// diagonal of `R`.
+    pub fn rank(&self) -> usize {
+        let columns = self.factors.x_len;
+        let diagonal: Vec<T> = (0..self.tau.len())
+            .map(|i| self.factors.data[i * columns + i].abs())
+            .collect();
+
+        let largest = diagonal
+            .iter()
+            .fold(T::zero(), |max, x| if *x > max { *x } else { max });
+        let tolerance =
+            largest * T::from_usize(columns.max(self.factors.y_len)) * T::EPSILON;
+
+        diagonal.iter().filter(|x| **x > tolerance).count()
+    }
+
+    /// Finds the `x` which minimises the 2-norm of `A * x - b`, returning it
+    /// together with the norm of the residual. Returns
+    /// `Error::RankDeficient` if the columns of `A` are not linearly
+    /// independent.
+    pub fn least_squares(&self, b: &[T]) -> Result<(Vec<T>, T)> {
+        let columns = self.factors.x_len;
+        let rows = self.factors.y_len;
+        if b.len() != rows {
+            return Err(Error::IncorrectLength {
+                expected: rows,
+                found: b.len(),
+            });
+        }
+
+        let rank = self.rank();
+        if rank < columns {
+            return Err(Error::RankDeficient {
+                rank,
+                required: columns,
+            });
+        }
+
+        let mut c = b.to_vec();
+        self.apply_q_transpose(&mut c);
+
+        let data = &self.factors.data;
+        let mut x = c[..columns].to_vec();
+        for i in (0..columns).rev() {
+            for j in i + 1..columns {
+                let cell = data[i * columns + j] * x[j];
+                x[i] -= cell;
+            }
+            x[i] /= data[i * columns + i];
+        }
+
+        let mut residual = T::zero();
+        for cell in &c[columns..] {
+            residual += *cell * *cell;
+        }
+
+        Ok((x, residual.sqrt()))
+    }
+}
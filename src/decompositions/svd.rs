@@ -0,0 +1,267 @@
+use crate::{
+    common::Real,
+    decompositions::DEFAULT_MAX_SWEEPS,
+    error::{Error, Result},
+    implementations::HeapMatrix,
+    matrix::*,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+/// The thin singular value decomposition of an `m x n` Matrix, such that
+/// `A = U * S * V^T` where `U` is `m x k`, `S` is a `k x k` diagonal Matrix of
+/// singular values and `V^T` is `k x n`, with `k = min(m, n)`. It is computed
+/// with the one-sided Jacobi algorithm.
+pub struct SingularValueDecomposition<T: Real> {
+    u: HeapMatrix<T>,
+    singular_values: Vec<T>,
+    v_t: HeapMatrix<T>,
+}
+
+impl<T: Real> SingularValueDecomposition<T> {
+    /// Decomposes the given Matrix, performing at most `DEFAULT_MAX_SWEEPS`
+    /// sweeps. See [`SingularValueDecomposition::with_max_sweeps`].
+    pub fn new<'a>(mat: &impl MatrixRef<'a, T>) -> Result<Self> {
+        Self::with_max_sweeps(mat, DEFAULT_MAX_SWEEPS)
+    }
+
+    /// Decomposes the given Matrix, returning `Error::NoConvergence` if the
+    /// columns have not been orthogonalised after `max_sweeps` sweeps.
+    pub fn with_max_sweeps<'a>(
+        mat: &impl MatrixRef<'a, T>,
+        max_sweeps: usize,
+    ) -> Result<Self> {
+        let columns = mat.get_x_len();
+        let rows = mat.get_y_len();
+
+        // The one-sided Jacobi algorithm orthogonalises columns, so a wide
+        // Matrix is decomposed through its transpose instead.
+        if columns > rows {
+            let mut transpose = vec![T::zero(); columns * rows];
+            for y in 0..rows {
+                for x in 0..columns {
+                    transpose[x * rows + y] = mat.get_at_unchecked(x, y);
+                }
+            }
+
+            let svd = Self::decompose(transpose, rows, columns, max_sweeps)?;
+            return Ok(Self {
+                u: transpose_of(&svd.v_t),
+                singular_values: svd.singular_values,
+                v_t: transpose_of(&svd.u),
+            });
+        }
+
+        Self::decompose(mat.to_vec(), columns, rows, max_sweeps)
+    }
+
+    /// Decomposes the row-major `data`, which must have at least as many rows
+    /// as columns.
+    fn decompose(
+        mut u: Vec<T>,
+        columns: usize,
+        rows: usize,
+        max_sweeps: usize,
+    ) -> Result<Self> {
+        let n = columns;
+        let mut v = vec![T::zero(); n * n];
+        for i in 0..n {
+            v[i * n + i] = T::one();
+        }
+
+        let two = T::one() + T::one();
+        let needs_rotation = |alpha: T, beta: T, gamma: T| {
+            gamma.abs() > T::EPSILON * (alpha * beta).sqrt()
+        };
+        let orthogonal = |u: &[T]| {
+            (0..n).all(|p| {
+                (p + 1..n).all(|q| {
+                    let (alpha, beta, gamma) = column_products(u, n, rows, p, q);
+                    !needs_rotation(alpha, beta, gamma)
+                })
+            })
+        };
+
+        let mut sweeps = 0;
+        while !orthogonal(&u) {
+            if sweeps == max_sweeps {
+                return Err(Error::NoConvergence {
+                    iterations: max_sweeps,
+                });
+            }
+            sweeps += 1;
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    let (alpha, beta, gamma) = column_products(&u, n, rows, p, q);
+                    if !needs_rotation(alpha, beta, gamma) {
+                        continue;
+                    }
+
+                    let zeta = (beta - alpha) / (two * gamma);
+                    let t = T::one() / (zeta.abs() + (zeta * zeta + T::one()).sqrt());
+                    let t = if zeta < T::zero() { T::zero() - t } else { t };
+                    let c = T::one() / (t * t + T::one()).sqrt();
+                    let s = t * c;
+
+                    rotate_columns(&mut u, n, rows, p, q, c, s);
+                    rotate_columns(&mut v, n, n, p, q, c, s);
+                }
+            }
+        }
+
+        let norms: Vec<T> = (0..n)
+            .map(|j| {
+                (0..rows)
+                    .fold(T::zero(), |sum, i| sum + u[i * n + j] * u[i * n + j])
+                    .sqrt()
+            })
+            .collect();
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|i, j| {
+            norms[*j]
+                .partial_cmp(&norms[*i])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let mut sorted_u = vec![T::zero(); rows * n];
+        let mut v_t = vec![T::zero(); n * n];
+        for (k, j) in order.iter().enumerate() {
+            if norms[*j] != T::zero() {
+                for i in 0..rows {
+                    sorted_u[i * n + k] = u[i * n + j] / norms[*j];
+                }
+            }
+
+            for i in 0..n {
+                v_t[k * n + i] = v[i * n + j];
+            }
+        }
+
+        Ok(Self {
+            u: HeapMatrix::new(&sorted_u, n, rows),
+            singular_values: order.iter().map(|j| norms[*j]).collect(),
+            v_t: HeapMatrix::new(&v_t, n, n),
+        })
+    }
+
+    /// Gets the `m x k` factor `U`. Columns which correspond to a singular
+    /// value of zero are zero.
+    pub fn u(&self) -> &HeapMatrix<T> { &self.u }
+
+    /// Gets the singular values in descending order.
+    pub fn singular_values(&self) -> &[T] { &self.singular_values }
+
+    /// Gets the `k x n` factor `V^T`.
+    pub fn v_t(&self) -> &HeapMatrix<T> { &self.v_t }
+
+    /// Returns the tolerance below which singular values are treated as zero
+    /// by `rank` and `pseudo_inverse`.
+    pub fn default_tolerance(&self) -> T {
+        let size = self.u.y_len.max(self.v_t.x_len);
+
+        self.norm_2() * T::from_usize(size) * T::EPSILON
+    }
+
+    /// Returns the numerical rank of the decomposed Matrix, which is the amount
+    /// of singular values greater than `default_tolerance`.
+    pub fn rank(&self) -> usize { self.rank_with_tolerance(self.default_tolerance()) }
+
+    /// Returns the amount of singular values greater than `tolerance`.
+    pub fn rank_with_tolerance(&self, tolerance: T) -> usize {
+        self.singular_values
+            .iter()
+            .filter(|x| **x > tolerance)
+            .count()
+    }
+
+    /// Returns the 2-norm of the decomposed Matrix, which is its largest
+    /// singular value.
+    pub fn norm_2(&self) -> T {
+        self.singular_values.first().copied().unwrap_or(T::zero())
+    }
+
+    /// Returns the 2-norm condition number of the decomposed Matrix, which is
+    /// the ratio of its largest singular value to its smallest.
+    pub fn condition_number(&self) -> T {
+        match self.singular_values.last() {
+            Some(smallest) => self.norm_2() / *smallest,
+            None => T::zero(),
+        }
+    }
+
+    /// Returns the Moore-Penrose pseudo-inverse of the decomposed Matrix,
+    /// treating singular values below `default_tolerance` as zero.
+    pub fn pseudo_inverse(&self) -> HeapMatrix<T> {
+        let rows = self.u.y_len;
+        let columns = self.v_t.x_len;
+        let k = self.singular_values.len();
+        let tolerance = self.default_tolerance();
+
+        let mut data = vec![T::zero(); columns * rows];
+        for (l, sigma) in self.singular_values.iter().enumerate() {
+            if *sigma <= tolerance {
+                continue;
+            }
+
+            for i in 0..columns {
+                let scaled = self.v_t.data[l * columns + i] / *sigma;
+                for j in 0..rows {
+                    data[i * rows + j] += scaled * self.u.data[j * k + l];
+                }
+            }
+        }
+
+        HeapMatrix::new(&data, rows, columns)
+    }
+}
+
+/// Returns the transpose of the given HeapMatrix.
+fn transpose_of<T: Real>(mat: &HeapMatrix<T>) -> HeapMatrix<T> {
+    let mut data = vec![T::zero(); mat.data.len()];
+    for y in 0..mat.y_len {
+        for x in 0..mat.x_len {
+            data[x * mat.y_len + y] = mat.data[y * mat.x_len + x];
+        }
+    }
+
+    HeapMatrix::new(&data, mat.y_len, mat.x_len)
+}
+
+/// Returns the squared norms of the columns `p` and `q` of the row-major `data`
+/// and their dot product.
+fn column_products<T: Real>(
+    data: &[T],
+    width: usize,
+    height: usize,
+    p: usize,
+    q: usize,
+) -> (T, T, T) {
+    let (mut alpha, mut beta, mut gamma) = (T::zero(), T::zero(), T::zero());
+    for i in 0..height {
+        let (x, y) = (data[i * width + p], data[i * width + q]);
+        alpha += x * x;
+        beta += y * y;
+        gamma += x * y;
+    }
+
+    (alpha, beta, gamma)
+}
+
+/// Applies a Jacobi rotation to the columns `p` and `q` of the row-major
+/// `data`.
+fn rotate_columns<T: Real>(
+    data: &mut [T],
+    width: usize,
+    height: usize,
+    p: usize,
+    q: usize,
+    c: T,
+    s: T,
+) {
+    for i in 0..height {
+        let (x, y) = (data[i * width + p], data[i * width + q]);
+        data[i * width + p] = c * x - s * y;
+        data[i * width + q] = s * x + c * y;
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{
+    common::Real,
+    error::{Error, Result},
+    implementations::HeapMatrix,
+    matrix::*,
+};
+
+/// The default amount of Jacobi sweeps to perform before giving up, used by
+/// both `SymmetricEigen` and `SingularValueDecomposition`.
+pub const DEFAULT_MAX_SWEEPS: usize = 100;
+
+#[derive(Debug, Clone, PartialEq)]
+/// The eigendecomposition of a real symmetric Matrix, such that
+/// `A = V * D * V^T` where `V` is orthogonal and `D` is diagonal. It is
+/// computed with the cyclic Jacobi eigenvalue algorithm.
+pub struct SymmetricEigen<T: Real> {
+    eigenvalues: Vec<T>,
+    eigenvectors: HeapMatrix<T>,
+}
+
+impl<T: Real> SymmetricEigen<T> {
+    /// Decomposes the given Matrix, performing at most `DEFAULT_MAX_SWEEPS`
+    /// sweeps. See [`SymmetricEigen::with_max_sweeps`].
+    pub fn new<'a>(mat: &impl MatrixRef<'a, T>) -> Result<Self> {
+        Self::with_max_sweeps(mat, DEFAULT_MAX_SWEEPS)
+    }
+
+    /// Decomposes the given Matrix, returning `Error::NotSquare` if it is not
+    /// square or `Error::NoConvergence` if the off-diagonal elements have not
+    /// vanished after `max_sweeps` sweeps. Only the lower triangle of the
+    /// Matrix is read, so it is assumed to be symmetric.
+    pub fn with_max_sweeps<'a>(
+        mat: &impl MatrixRef<'a, T>,
+        max_sweeps: usize,
+    ) -> Result<Self> {
+        check_square(mat)?;
+
+        let n = mat.get_x_len();
+        let mut a = vec![T::zero(); n * n];
+        let mut v = vec![T::zero(); n * n];
+        for i in 0..n {
+            for j in 0..=i {
+                a[i * n + j] = mat.get_at_unchecked(j, i);
+                a[j * n + i] = a[i * n + j];
+            }
+            v[i * n + i] = T::one();
+        }
+
+        let norm = a.iter().fold(T::zero(), |sum, x| sum + *x * *x);
+        let tolerance = norm * T::EPSILON * T::EPSILON;
+        let two = T::one() + T::one();
+        let off_diagonal = |a: &[T]| {
+            (0..n).fold(T::zero(), |sum, p| {
+                (p + 1..n).fold(sum, |sum, q| sum + a[p * n + q] * a[p * n + q])
+            })
+        };
+
+        let mut sweeps = 0;
+        while off_diagonal(&a) > tolerance {
+            if sweeps == max_sweeps {
+                return Err(Error::NoConvergence {
+                    iterations: max_sweeps,
+                });
+            }
+            sweeps += 1;
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    if a[p * n + q] == T::zero() {
+                        continue;
+                    }
+
+                    let theta = (a[q * n + q] - a[p * n + p]) / (two * a[p * n + q]);
+                    let t = T::one() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                    let t = if theta < T::zero() { T::zero() - t } else { t };
+                    let c = T::one() / (t * t + T::one()).sqrt();
+                    let s = t * c;
+
+                    rotate(&mut a, n, p, q, c, s, true);
+                    rotate(&mut a, n, p, q, c, s, false);
+                    rotate(&mut v, n, p, q, c, s, true);
+                }
+            }
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|i, j| {
+            a[i * n + i]
+                .partial_cmp(&a[j * n + j])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let eigenvalues = order.iter().map(|i| a[i * n + i]).collect();
+        let mut eigenvectors = vec![T::zero(); n * n];
+        for (column, i) in order.iter().enumerate() {
+            for y in 0..n {
+                eigenvectors[y * n + column] = v[y * n + i];
+            }
+        }
+
+        Ok(Self {
+            eigenvalues,
+            eigenvectors: HeapMatrix::new(&eigenvectors, n, n),
+        })
+    }
+
+    /// Gets the eigenvalues in ascending order.
+    pub fn eigenvalues(&self) -> &[T] { &self.eigenvalues }
+
+    /// Gets the orthonormal eigenvectors as the columns of a Matrix, where
+    /// column `i` corresponds to `eigenvalues()[i]`.
+    pub fn eigenvectors(&self) -> &HeapMatrix<T> { &self.eigenvectors }
+}
+
+/// Applies a Jacobi rotation in the `(p, q)` plane to either the columns or the
+/// rows of the `n x n` row-major `data`.
+fn rotate<T: Real>(
+    data: &mut [T],
+    n: usize,
+    p: usize,
+    q: usize,
+    c: T,
+    s: T,
+    columns: bool,
+) {
+    for k in 0..n {
+        let (kp, kq) = if columns {
+            (k * n + p, k * n + q)
+        } else {
+            (p * n + k, q * n + k)
+        };
+
+        let (x, y) = (data[kp], data[kq]);
+        data[kp] = c * x - s * y;
+        data[kq] = s * x + c * y;
+    }
+}
@@ -1,16 +1,100 @@
-#[derive(Debug, PartialEq)]
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 /// rusty-matrix specific Error enum.
 pub enum Error {
     /// NotEq: Two Matrices were expected to be equal in one way or another.
     NotEq,
     /// OutOfRange: Returned when attempting to index the Matrix with an
-    /// out-of-range x and y coordinates.
-    OutOfRange,
+    /// out-of-range x and y coordinates. Both the coordinates and the bounds
+    /// are given as `(x, y)`.
+    OutOfRange {
+        index: (usize, usize),
+        bounds: (usize, usize),
+    },
     /// IncorrectLength: Returned when passing a Vec<T> as the data to construct
     /// a Matrix which is too long or short for the specified Matrix type.
-    IncorrectLength,
+    IncorrectLength { expected: usize, found: usize },
+    /// InvalidOffset: Returned when the offsets of a compressed sparse Matrix
+    /// do not start at zero or decrease. Gives the position of the first
+    /// invalid offset and its value.
+    InvalidOffset { index: usize, offset: usize },
+    /// DimensionMismatch: Returned when an operation is given two Matrices
+    /// whose dimensions are incompatible. Dimensions are given as `(x_len,
+    /// y_len)`.
+    DimensionMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    /// NotSquare: Returned when an operation that is only defined for square
+    /// Matrices is given a Matrix with the dimensions `(x_len, y_len)`.
+    NotSquare { dimensions: (usize, usize) },
+    /// Singular: Returned when an operation requires an invertible Matrix but
+    /// the given Matrix is singular.
+    Singular,
+    /// NotRepresentable: Returned when the exact result of an operation cannot
+    /// be represented by the element type, such as the inverse of an integer
+    /// Matrix whose elements are not all integers.
+    NotRepresentable,
+    /// NotPositiveDefinite: Returned when an operation requires a symmetric
+    /// positive-definite Matrix but the given Matrix is not positive-definite.
+    NotPositiveDefinite,
+    /// RankDeficient: Returned when an operation requires a Matrix of full
+    /// rank but the given Matrix only has the given rank.
+    RankDeficient { rank: usize, required: usize },
+    /// NoConvergence: Returned when an iterative algorithm fails to converge
+    /// within the given amount of iterations.
+    NoConvergence { iterations: usize },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NotEq => write!(f, "matrices were expected to be equal"),
+            Error::OutOfRange { index, bounds } => write!(
+                f,
+                "index ({}, {}) is out of range for a matrix with dimensions ({}, {})",
+                index.0, index.1, bounds.0, bounds.1
+            ),
+            Error::IncorrectLength { expected, found } => write!(
+                f,
+                "expected data of length {} but found length {}",
+                expected, found
+            ),
+            Error::InvalidOffset { index, offset } => write!(
+                f,
+                "offset {} at position {} does not follow the previous offset",
+                offset, index
+            ),
+            Error::DimensionMismatch { expected, found } => write!(
+                f,
+                "expected a matrix with dimensions ({}, {}) but found ({}, {})",
+                expected.0, expected.1, found.0, found.1
+            ),
+            Error::NotSquare { dimensions } => write!(
+                f,
+                "expected a square matrix but found dimensions ({}, {})",
+                dimensions.0, dimensions.1
+            ),
+            Error::Singular => write!(f, "matrix is singular"),
+            Error::NotRepresentable => {
+                write!(f, "result cannot be represented by the element type")
+            }
+            Error::NotPositiveDefinite => write!(f, "matrix is not positive-definite"),
+            Error::RankDeficient { rank, required } => write!(
+                f,
+                "matrix has rank {} but a rank of {} is required",
+                rank, required
+            ),
+            Error::NoConvergence { iterations } => {
+                write!(f, "failed to converge after {} iterations", iterations)
+            }
+        }
+    }
 }
 
+impl std::error::Error for Error {}
+
 /// rusty-matrix built-in Result type for use with the rusty-matrix Matrix
 /// functions.
 pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,331 @@
+use crate::{
+    common::{Field, Numeric, Ring, Semiring},
+    error::{Error, Result},
+    implementations::HeapMatrix,
+    matrix::*,
+};
+use std::{
+    fmt::{Display, Formatter},
+    ops::*,
+};
+
+#[derive(Debug, Clone)]
+/// A square Matrix whose non-zero elements are all within `lower` diagonals
+/// below and `upper` diagonals above the main diagonal. Only the band is
+/// stored, row by row: each row holds `lower + upper + 1` elements, starting
+/// from the column `y - lower`, and the elements of the band which fall
+/// outside of the Matrix are stored as zero.
+pub struct BandedMatrix<T: Numeric> {
+    pub(crate) data: Vec<T>,
+    pub(crate) n: usize,
+    pub(crate) lower: usize,
+    pub(crate) upper: usize,
+}
+
+impl<T: Numeric> BandedMatrix<T> {
+    /// Creates a new `n` by `n` BandedMatrix from its band, given row by row,
+    /// returning `Error::IncorrectLength` if `data` does not have
+    /// `n * (lower + upper + 1)` elements. The elements of the band which fall
+    /// outside of the Matrix are ignored.
+    pub fn new(n: usize, lower: usize, upper: usize, data: &[T]) -> Result<Self> {
+        let width = lower + upper + 1;
+        if data.len() != n * width {
+            return Err(Error::IncorrectLength {
+                expected: n * width,
+                found: data.len(),
+            });
+        }
+
+        let mut banded = Self {
+            data: data.to_vec(),
+            n,
+            lower,
+            upper,
+        };
+        for y in 0..n {
+            for i in 0..width {
+                if (y + i) < lower || y + i - lower >= n {
+                    banded.data[y * width + i] = T::zero();
+                }
+            }
+        }
+
+        Ok(banded)
+    }
+
+    /// Creates a new tridiagonal BandedMatrix from its sub-diagonal, diagonal
+    /// and super-diagonal, returning `Error::IncorrectLength` if the sub- and
+    /// super-diagonals are not one element shorter than the diagonal.
+    pub fn tridiagonal(lower: &[T], diagonal: &[T], upper: &[T]) -> Result<Self> {
+        let n = diagonal.len();
+        for off_diagonal in [lower, upper] {
+            if off_diagonal.len() != n.saturating_sub(1) {
+                return Err(Error::IncorrectLength {
+                    expected: n.saturating_sub(1),
+                    found: off_diagonal.len(),
+                });
+            }
+        }
+
+        let mut data = vec![T::zero(); 3 * n];
+        for y in 0..n {
+            if y > 0 {
+                data[y * 3] = lower[y - 1];
+            }
+            data[y * 3 + 1] = diagonal[y];
+            if y + 1 < n {
+                data[y * 3 + 2] = upper[y];
+            }
+        }
+
+        Ok(Self {
+            data,
+            n,
+            lower: 1,
+            upper: 1,
+        })
+    }
+
+    /// Creates a new BandedMatrix from the band of any other Matrix, returning
+    /// `Error::NotSquare` if it is not square. The elements outside of the band
+    /// are not read, so they are assumed to be zero.
+    pub fn from_dense<'a>(
+        mat: &impl MatrixRef<'a, T>,
+        lower: usize,
+        upper: usize,
+    ) -> Result<Self> {
+        check_square(mat)?;
+
+        let n = mat.get_x_len();
+        let width = lower + upper + 1;
+        let mut data = vec![T::zero(); n * width];
+        for y in 0..n {
+            for x in y.saturating_sub(lower)..n.min(y + upper + 1) {
+                data[y * width + x + lower - y] = mat.get_at_unchecked(x, y);
+            }
+        }
+
+        Ok(Self {
+            data,
+            n,
+            lower,
+            upper,
+        })
+    }
+
+    /// Copies the BandedMatrix into a new HeapMatrix.
+    pub fn to_heap_matrix(&self) -> HeapMatrix<T> {
+        HeapMatrix::new(&self.to_vec(), self.n, self.n)
+    }
+
+    /// Gets the amount of diagonals below the main diagonal which are stored.
+    pub fn lower_bandwidth(&self) -> usize { self.lower }
+
+    /// Gets the amount of diagonals above the main diagonal which are stored.
+    pub fn upper_bandwidth(&self) -> usize { self.upper }
+
+    /// Gets the stored band, row by row.
+    pub fn data(&self) -> &[T] { &self.data }
+
+    fn width(&self) -> usize { self.lower + self.upper + 1 }
+
+    /// Gets the columns of the row `y` which are within the band.
+    fn band_columns(&self, y: usize) -> Range<usize> {
+        y.saturating_sub(self.lower)..self.n.min(y + self.upper + 1)
+    }
+
+    /// Gets the position of the element at `(x, y)`, which must be within the
+    /// band, in the stored data.
+    fn index(&self, x: usize, y: usize) -> usize { y * self.width() + x + self.lower - y }
+
+    fn contains(&self, x: usize, y: usize) -> bool {
+        x + self.lower >= y && x <= y + self.upper
+    }
+}
+
+impl<T: Semiring> BandedMatrix<T> {
+    /// Multiplies the BandedMatrix by the vector `x` in O(n * bandwidth) time,
+    /// returning `Error::IncorrectLength` if `x` does not have `n` elements.
+    pub fn mul_vec(&self, x: &[T]) -> Result<Vec<T>> {
+        check_vec_len(self.n, x)?;
+
+        Ok((0..self.n)
+            .map(|y| {
+                self.band_columns(y).fold(T::zero(), |sum, column| {
+                    sum + self.data[self.index(column, y)] * x[column]
+                })
+            })
+            .collect())
+    }
+
+    /// Computes the matrix product of the BandedMatrix and any other Matrix,
+    /// returning `Error::DimensionMismatch` if `rhs` does not have `n` rows.
+    pub fn mul_dense<'a>(&self, rhs: &impl MatrixRef<'a, T>) -> Result<HeapMatrix<T>> {
+        map_columns(self.n, rhs, |column| self.mul_vec(column))
+    }
+
+    /// Builds a BandedMatrix with the given bandwidths from a function of the
+    /// coordinates within the band.
+    fn from_band_fn(
+        n: usize,
+        lower: usize,
+        upper: usize,
+        f: impl Fn(usize, usize) -> T,
+    ) -> Self {
+        let mut banded = Self {
+            data: vec![T::zero(); n * (lower + upper + 1)],
+            n,
+            lower,
+            upper,
+        };
+        for y in 0..n {
+            for x in banded.band_columns(y) {
+                let index = banded.index(x, y);
+                banded.data[index] = f(x, y);
+            }
+        }
+
+        banded
+    }
+}
+
+impl<T: Field> BandedMatrix<T> {
+    /// Solves `A * x = b` for `x` by Gaussian elimination within the band,
+    /// which takes O(n * lower * upper) time. Returns `Error::IncorrectLength`
+    /// if `b` does not have `n` elements or `Error::Singular` if a pivot is
+    /// zero. Rows are not exchanged, as that would widen the band, so this is
+    /// intended for Matrices which do not need pivoting, such as diagonally
+    /// dominant or positive-definite ones.
+    pub fn solve_vec(&self, b: &[T]) -> Result<Vec<T>> {
+        check_vec_len(self.n, b)?;
+
+        let n = self.n;
+        let mut factors = self.data.clone();
+        let mut x = b.to_vec();
+
+        for k in 0..n {
+            let pivot = factors[self.index(k, k)];
+            if pivot.is_zero() {
+                return Err(Error::Singular);
+            }
+
+            for y in k + 1..n.min(k + self.lower + 1) {
+                let factor = factors[self.index(k, y)] / pivot;
+                // The elements of row k after the pivot are within the band
+                // of row y, as y is below k.
+                for column in k..n.min(k + self.upper + 1) {
+                    let cell = factor * factors[self.index(column, k)];
+                    factors[self.index(column, y)] -= cell;
+                }
+
+                let cell = factor * x[k];
+                x[y] -= cell;
+            }
+        }
+
+        for y in (0..n).rev() {
+            for column in y + 1..n.min(y + self.upper + 1) {
+                let cell = factors[self.index(column, y)] * x[column];
+                x[y] -= cell;
+            }
+            x[y] /= factors[self.index(y, y)];
+        }
+
+        Ok(x)
+    }
+
+    /// Solves `A * X = B` for `X`, treating each column of `B` as a separate
+    /// right-hand side. Returns `Error::DimensionMismatch` if `B` does not
+    /// have `n` rows or `Error::Singular` if a pivot is zero.
+    pub fn solve<'a>(&self, b: &impl MatrixRef<'a, T>) -> Result<HeapMatrix<T>> {
+        map_columns(self.n, b, |column| self.solve_vec(column))
+    }
+}
+
+impl<T: Semiring> Add for BandedMatrix<T> {
+    type Output = Self;
+
+    // The sum has the wider of the two bands.
+    fn add(self, rhs: Self) -> Self::Output {
+        check_same_dimensions(&self, &rhs).unwrap_or_else(|error| panic!("{}", error));
+
+        Self::from_band_fn(
+            self.n,
+            self.lower.max(rhs.lower),
+            self.upper.max(rhs.upper),
+            |x, y| self.get_at_unchecked(x, y) + rhs.get_at_unchecked(x, y),
+        )
+    }
+}
+
+impl<T: Ring> Sub for BandedMatrix<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        check_same_dimensions(&self, &rhs).unwrap_or_else(|error| panic!("{}", error));
+
+        Self::from_band_fn(
+            self.n,
+            self.lower.max(rhs.lower),
+            self.upper.max(rhs.upper),
+            |x, y| self.get_at_unchecked(x, y) - rhs.get_at_unchecked(x, y),
+        )
+    }
+}
+
+impl<T: Semiring> Mul for BandedMatrix<T> {
+    type Output = Self;
+
+    // The bandwidths of the product are the sums of those of the operands.
+    fn mul(self, rhs: Self) -> Self::Output {
+        check_product_dimensions(&self, &rhs).unwrap_or_else(|error| panic!("{}", error));
+
+        let n = self.n;
+        let lower = (self.lower + rhs.lower).min(n.saturating_sub(1));
+        let upper = (self.upper + rhs.upper).min(n.saturating_sub(1));
+
+        Self::from_band_fn(n, lower, upper, |x, y| {
+            self.band_columns(y)
+                .filter(|k| rhs.contains(x, *k))
+                .fold(T::zero(), |sum, k| {
+                    sum + self.data[self.index(k, y)] * rhs.data[rhs.index(x, k)]
+                })
+        })
+    }
+}
+
+// BandedMatrices with the same elements may store bands of different widths,
+// so they are compared element by element within the wider of the two bands.
+impl<T: Numeric> PartialEq for BandedMatrix<T> {
+    fn eq(&self, other: &Self) -> bool {
+        let (lower, upper) = (self.lower.max(other.lower), self.upper.max(other.upper));
+
+        self.n == other.n
+            && (0..self.n).all(|y| {
+                (y.saturating_sub(lower)..self.n.min(y + upper + 1))
+                    .all(|x| self.get_at_unchecked(x, y) == other.get_at_unchecked(x, y))
+            })
+    }
+}
+
+impl_structured_ops!(BandedMatrix);
+
+impl<'a, T: 'a + Numeric> MatrixRef<'a, T> for BandedMatrix<T> {
+    fn get_x_len(&self) -> usize { self.n }
+    fn get_y_len(&self) -> usize { self.n }
+
+    fn get_at_unchecked(&self, x: usize, y: usize) -> T {
+        assert!(
+            x < self.n && y < self.n,
+            "Index ({}, {}) is out of range",
+            x,
+            y
+        );
+
+        if self.contains(x, y) {
+            self.data[self.index(x, y)]
+        } else {
+            T::zero()
+        }
+    }
+}
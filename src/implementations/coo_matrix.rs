@@ -0,0 +1,146 @@
+use crate::{
+    common::{Numeric, Semiring},
+    error::{Error, Result},
+    implementations::{CscMatrix, SparseMatrix},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+/// A builder for sparse Matrices which collects elements as `(x, y, value)`
+/// triplets in coordinate (COO) form. Elements can be pushed in any order and
+/// the same position may be pushed more than once, in which case the values
+/// are summed when the builder is compressed with `to_csr` or `to_csc`.
+pub struct CooMatrix<T: Numeric> {
+    pub(crate) column_indices: Vec<usize>,
+    pub(crate) row_indices: Vec<usize>,
+    pub(crate) values: Vec<T>,
+    pub(crate) x_len: usize,
+    pub(crate) y_len: usize,
+}
+
+impl<T: Numeric> CooMatrix<T> {
+    /// Creates a new, empty CooMatrix with the given dimensions.
+    pub fn new(columns: usize, rows: usize) -> Self {
+        Self::with_capacity(columns, rows, 0)
+    }
+
+    /// Creates a new, empty CooMatrix with the given dimensions and space for
+    /// `capacity` triplets.
+    pub fn with_capacity(columns: usize, rows: usize, capacity: usize) -> Self {
+        Self {
+            column_indices: Vec::with_capacity(capacity),
+            row_indices: Vec::with_capacity(capacity),
+            values: Vec::with_capacity(capacity),
+            x_len: columns,
+            y_len: rows,
+        }
+    }
+
+    /// Adds `value` to the element at the given x and y index, returning
+    /// `Error::OutOfRange` if the index is not in the CooMatrix.
+    pub fn push(&mut self, x: usize, y: usize, value: T) -> Result<()> {
+        if x >= self.x_len || y >= self.y_len {
+            return Err(Error::OutOfRange {
+                index: (x, y),
+                bounds: (self.x_len, self.y_len),
+            });
+        }
+
+        self.column_indices.push(x);
+        self.row_indices.push(y);
+        self.values.push(value);
+
+        Ok(())
+    }
+
+    /// Returns the amount of triplets that have been pushed, including
+    /// duplicates and zeros.
+    pub fn len(&self) -> usize { self.values.len() }
+
+    /// Returns whether no triplets have been pushed.
+    pub fn is_empty(&self) -> bool { self.values.is_empty() }
+
+    /// Removes every triplet, keeping the dimensions and allocated capacity.
+    pub fn clear(&mut self) {
+        self.column_indices.clear();
+        self.row_indices.clear();
+        self.values.clear();
+    }
+
+    /// Gets the triplets that have been pushed, in the order they were pushed.
+    pub fn triplets(&self) -> impl Iterator<Item = (usize, usize, T)> + '_ {
+        self.column_indices
+            .iter()
+            .zip(&self.row_indices)
+            .zip(&self.values)
+            .map(|((x, y), value)| (*x, *y, *value))
+    }
+}
+
+impl<T: Semiring> CooMatrix<T> {
+    /// Compresses the triplets into a SparseMatrix, summing duplicates and
+    /// dropping zeros.
+    pub fn to_csr(&self) -> SparseMatrix<T> {
+        let mut sparse = SparseMatrix::new(self.x_len, self.y_len);
+        let rows = group_by_lane(
+            self.y_len,
+            &self.row_indices,
+            &self.column_indices,
+            &self.values,
+        );
+        for (y, row) in rows.into_iter().enumerate() {
+            sparse.push_row(y, row);
+        }
+
+        sparse
+    }
+
+    /// Compresses the triplets into a CscMatrix, summing duplicates and
+    /// dropping zeros.
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let mut sparse = CscMatrix::new(self.x_len, self.y_len);
+        let columns = group_by_lane(
+            self.x_len,
+            &self.column_indices,
+            &self.row_indices,
+            &self.values,
+        );
+        for (x, column) in columns.into_iter().enumerate() {
+            sparse.push_column(x, column);
+        }
+
+        sparse
+    }
+}
+
+/// Splits the triplets into one list of `(inner index, value)` entries per
+/// lane, each sorted by its inner index.
+fn group_by_lane<T: Numeric>(
+    lanes: usize,
+    lane_indices: &[usize],
+    inner_indices: &[usize],
+    values: &[T],
+) -> Vec<Vec<(usize, T)>> {
+    let mut grouped = vec![Vec::new(); lanes];
+    for ((lane, inner), value) in lane_indices.iter().zip(inner_indices).zip(values) {
+        grouped[*lane].push((*inner, *value));
+    }
+    for lane in &mut grouped {
+        lane.sort_by_key(|(inner, _)| *inner);
+    }
+
+    grouped
+}
+
+impl<T: Numeric> From<&SparseMatrix<T>> for CooMatrix<T> {
+    fn from(sparse: &SparseMatrix<T>) -> Self {
+        let mut coo = Self::with_capacity(sparse.x_len, sparse.y_len, sparse.nnz());
+        for y in 0..sparse.y_len {
+            let (columns, values) = sparse.row_entries(y);
+            coo.column_indices.extend_from_slice(columns);
+            coo.row_indices.extend(columns.iter().map(|_| y));
+            coo.values.extend_from_slice(values);
+        }
+
+        coo
+    }
+}
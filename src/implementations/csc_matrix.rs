@@ -0,0 +1,348 @@
+use crate::{
+    common::{Numeric, Semiring},
+    error::{Error, Result},
+    implementations::{check_offsets, push_lane, HeapMatrix, SparseMatrix, StackMatrix},
+    matrix::*,
+};
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq)]
+/// A Matrix stored in compressed sparse column (CSC) form, which only stores
+/// its non-zero elements. The elements of column `x` are
+/// `values[column_offsets[x]..column_offsets[x + 1]]`, and the rows they are in
+/// are the same range of `row_indices`.
+///
+/// This is the column-wise counterpart of `SparseMatrix`: taking a column or a
+/// range of columns only copies the stored elements of those columns. Like
+/// `SparseMatrix`, the row indices within each column are kept sorted and
+/// zeros are never stored.
+pub struct CscMatrix<T: Numeric> {
+    pub(crate) column_offsets: Vec<usize>,
+    pub(crate) row_indices: Vec<usize>,
+    pub(crate) values: Vec<T>,
+    pub(crate) x_len: usize,
+    pub(crate) y_len: usize,
+}
+
+impl<T: Numeric> CscMatrix<T> {
+    /// Creates a new CscMatrix with the given dimensions where every element
+    /// is zero.
+    pub fn new(columns: usize, rows: usize) -> Self {
+        Self {
+            column_offsets: vec![0; columns + 1],
+            row_indices: Vec::new(),
+            values: Vec::new(),
+            x_len: columns,
+            y_len: rows,
+        }
+    }
+
+    /// Creates a new CscMatrix holding the non-zero elements of any other
+    /// Matrix.
+    pub fn from_dense<'a>(mat: &impl MatrixRef<'a, T>) -> Self {
+        let mut sparse = Self::new(mat.get_x_len(), mat.get_y_len());
+
+        for x in 0..mat.get_x_len() {
+            for y in 0..mat.get_y_len() {
+                let cell = mat.get_at_unchecked(x, y);
+                if !cell.is_zero() {
+                    sparse.row_indices.push(y);
+                    sparse.values.push(cell);
+                }
+            }
+            sparse.column_offsets[x + 1] = sparse.values.len();
+        }
+
+        sparse
+    }
+
+    /// Copies the CscMatrix into a new HeapMatrix.
+    pub fn to_heap_matrix(&self) -> HeapMatrix<T> {
+        HeapMatrix::new(&self.to_vec(), self.x_len, self.y_len)
+    }
+
+    /// Converts the CscMatrix into compressed sparse row form. The stored
+    /// elements are distributed straight into their rows, so this takes
+    /// O(nnz + rows) time.
+    pub fn to_csr(&self) -> SparseMatrix<T> {
+        let (row_offsets, column_indices, values) = recompress(
+            self.y_len,
+            &self.column_offsets,
+            &self.row_indices,
+            &self.values,
+        );
+
+        SparseMatrix {
+            row_offsets,
+            column_indices,
+            values,
+            x_len: self.x_len,
+            y_len: self.y_len,
+        }
+    }
+
+    /// Returns the amount of stored, non-zero elements.
+    pub fn nnz(&self) -> usize { self.values.len() }
+
+    /// Gets the offsets into the row indices and values at which each column
+    /// starts, followed by the amount of stored elements.
+    pub fn column_offsets(&self) -> &[usize] { &self.column_offsets }
+
+    /// Gets the row index of each stored element.
+    pub fn row_indices(&self) -> &[usize] { &self.row_indices }
+
+    /// Gets the value of each stored element.
+    pub fn values(&self) -> &[T] { &self.values }
+
+    /// Gets the row indices and values of the stored elements in the column at
+    /// the given x index, panicking if there is no such column.
+    pub fn column_entries(&self, x: usize) -> (&[usize], &[T]) {
+        assert!(x < self.x_len, "Column {} is out of range", x);
+        let range = self.column_offsets[x]..self.column_offsets[x + 1];
+
+        (&self.row_indices[range.clone()], &self.values[range])
+    }
+
+    /// Copies `width` columns starting at the column `x` into a new CscMatrix,
+    /// returning `Error::OutOfRange` if the columns do not fit in the
+    /// CscMatrix.
+    pub fn columns_slice(&self, x: usize, width: usize) -> Result<Self> {
+        if x + width > self.x_len {
+            return Err(Error::OutOfRange {
+                index: (x + width, 0),
+                bounds: (self.x_len, self.y_len),
+            });
+        }
+
+        let start = self.column_offsets[x];
+        let end = self.column_offsets[x + width];
+
+        Ok(Self {
+            column_offsets: self.column_offsets[x..=x + width]
+                .iter()
+                .map(|offset| offset - start)
+                .collect(),
+            row_indices: self.row_indices[start..end].to_vec(),
+            values: self.values[start..end].to_vec(),
+            x_len: width,
+            y_len: self.y_len,
+        })
+    }
+}
+
+impl<T: Semiring> CscMatrix<T> {
+    /// Creates a new CscMatrix from its CSC arrays, where `column_offsets` has
+    /// one more element than there are columns. The entries of each column
+    /// may be in any order; entries in the same position are summed and zeros
+    /// are dropped. Returns `Error::IncorrectLength` if the lengths of the
+    /// arrays are inconsistent, `Error::InvalidOffset` if the offsets do not
+    /// start at zero or decrease, or `Error::OutOfRange` if a row index is not
+    /// less than `rows`.
+    pub fn from_csc(
+        columns: usize,
+        rows: usize,
+        column_offsets: Vec<usize>,
+        row_indices: Vec<usize>,
+        values: Vec<T>,
+    ) -> Result<Self> {
+        if column_offsets.len() != columns + 1 {
+            return Err(Error::IncorrectLength {
+                expected: columns + 1,
+                found: column_offsets.len(),
+            });
+        }
+        if row_indices.len() != values.len() {
+            return Err(Error::IncorrectLength {
+                expected: values.len(),
+                found: row_indices.len(),
+            });
+        }
+        check_offsets(&column_offsets, values.len())?;
+
+        let mut sparse = Self::new(columns, rows);
+        for x in 0..columns {
+            let range = column_offsets[x]..column_offsets[x + 1];
+            let mut column: Vec<(usize, T)> = row_indices[range.clone()]
+                .iter()
+                .copied()
+                .zip(values[range].iter().copied())
+                .collect();
+
+            if let Some((y, _)) = column.iter().find(|(y, _)| *y >= rows) {
+                return Err(Error::OutOfRange {
+                    index: (x, *y),
+                    bounds: (columns, rows),
+                });
+            }
+
+            column.sort_by_key(|(y, _)| *y);
+            sparse.push_column(x, column);
+        }
+
+        Ok(sparse)
+    }
+
+    /// Fills in the column at the given x index from entries sorted by row,
+    /// summing entries in the same row and dropping zeros. Columns must be
+    /// filled in order, starting from the first.
+    pub(crate) fn push_column(
+        &mut self,
+        x: usize,
+        column: impl IntoIterator<Item = (usize, T)>,
+    ) {
+        push_lane(&mut self.row_indices, &mut self.values, column);
+        self.column_offsets[x + 1] = self.values.len();
+    }
+
+    /// Multiplies the CscMatrix by the vector `x`, returning
+    /// `Error::IncorrectLength` if `x` does not have as many elements as the
+    /// CscMatrix has columns.
+    pub fn mul_vec(&self, x: &[T]) -> Result<Vec<T>> {
+        if x.len() != self.x_len {
+            return Err(Error::IncorrectLength {
+                expected: self.x_len,
+                found: x.len(),
+            });
+        }
+
+        let mut product = vec![T::zero(); self.y_len];
+        for (column, scale) in x.iter().enumerate() {
+            let (rows, values) = self.column_entries(column);
+            for (y, value) in rows.iter().zip(values) {
+                product[*y] += *value * *scale;
+            }
+        }
+
+        Ok(product)
+    }
+}
+
+impl<T: Numeric> SparseMatrix<T> {
+    /// Converts the SparseMatrix into compressed sparse column form. The
+    /// stored elements are distributed straight into their columns, so this
+    /// takes O(nnz + columns) time.
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let (column_offsets, row_indices, values) = recompress(
+            self.x_len,
+            &self.row_offsets,
+            &self.column_indices,
+            &self.values,
+        );
+
+        CscMatrix {
+            column_offsets,
+            row_indices,
+            values,
+            x_len: self.x_len,
+            y_len: self.y_len,
+        }
+    }
+}
+
+/// Regroups the elements of a compressed sparse Matrix by their inner index,
+/// turning CSR arrays into CSC arrays and vice versa. Walking the outer lanes
+/// in order keeps the new inner indices sorted.
+fn recompress<T: Numeric>(
+    lanes: usize,
+    offsets: &[usize],
+    indices: &[usize],
+    values: &[T],
+) -> (Vec<usize>, Vec<usize>, Vec<T>) {
+    let mut new_offsets = vec![0; lanes + 1];
+    for index in indices {
+        new_offsets[index + 1] += 1;
+    }
+    for lane in 0..lanes {
+        new_offsets[lane + 1] += new_offsets[lane];
+    }
+
+    let mut next = new_offsets.clone();
+    let mut new_indices = vec![0; values.len()];
+    let mut new_values = vec![T::zero(); values.len()];
+    for outer in 0..offsets.len() - 1 {
+        for i in offsets[outer]..offsets[outer + 1] {
+            let position = &mut next[indices[i]];
+            new_indices[*position] = outer;
+            new_values[*position] = values[i];
+            *position += 1;
+        }
+    }
+
+    (new_offsets, new_indices, new_values)
+}
+
+impl<'a, T: 'a + Numeric> MatrixRef<'a, T> for CscMatrix<T> {
+    fn get_x_len(&self) -> usize { self.x_len }
+    fn get_y_len(&self) -> usize { self.y_len }
+
+    fn get_at_unchecked(&self, x: usize, y: usize) -> T {
+        assert!(
+            x < self.x_len && y < self.y_len,
+            "Index ({}, {}) is out of range",
+            x,
+            y
+        );
+
+        let (indices, values) = self.column_entries(x);
+        match indices.binary_search(&y) {
+            Ok(index) => values[index],
+            Err(_) => T::zero(),
+        }
+    }
+
+    fn to_vec(&self) -> Vec<T> {
+        let mut data = vec![T::zero(); self.x_len * self.y_len];
+        for x in 0..self.x_len {
+            let (indices, values) = self.column_entries(x);
+            for (y, value) in indices.iter().zip(values) {
+                data[y * self.x_len + x] = *value;
+            }
+        }
+
+        data
+    }
+}
+
+impl<T: Numeric> PartialEq<SparseMatrix<T>> for CscMatrix<T> {
+    fn eq(&self, other: &SparseMatrix<T>) -> bool { self.to_csr() == *other }
+}
+
+impl<T: Numeric> PartialEq<CscMatrix<T>> for SparseMatrix<T> {
+    fn eq(&self, other: &CscMatrix<T>) -> bool { other == self }
+}
+
+impl<T: Numeric> PartialEq<HeapMatrix<T>> for CscMatrix<T> {
+    fn eq(&self, other: &HeapMatrix<T>) -> bool {
+        self.x_len == other.x_len
+            && self.y_len == other.y_len
+            && self.to_vec() == *other.row_major_data()
+    }
+}
+
+impl<T: Numeric> PartialEq<CscMatrix<T>> for HeapMatrix<T> {
+    fn eq(&self, other: &CscMatrix<T>) -> bool { other == self }
+}
+
+impl<T: Numeric, const X: usize, const Y: usize> PartialEq<StackMatrix<T, X, Y>>
+    for CscMatrix<T>
+where
+    [T; X * Y]: Sized,
+{
+    fn eq(&self, other: &StackMatrix<T, X, Y>) -> bool {
+        self.x_len == X && self.y_len == Y && self.to_vec() == other.data
+    }
+}
+
+impl<T: Numeric, const X: usize, const Y: usize> PartialEq<CscMatrix<T>>
+    for StackMatrix<T, X, Y>
+where
+    [T; X * Y]: Sized,
+{
+    fn eq(&self, other: &CscMatrix<T>) -> bool { other == self }
+}
+
+impl<T: Numeric + Display> Display for CscMatrix<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_printable())
+    }
+}
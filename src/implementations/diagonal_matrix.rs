@@ -0,0 +1,198 @@
+use crate::{
+    common::{Field, Numeric, Ring, Semiring},
+    error::{Error, Result},
+    implementations::HeapMatrix,
+    matrix::*,
+};
+use std::{
+    fmt::{Display, Formatter},
+    ops::*,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+/// A square Matrix whose only non-zero elements are on its diagonal, which is
+/// the only part of it that is stored.
+pub struct DiagonalMatrix<T: Numeric> {
+    pub(crate) diagonal: Vec<T>,
+}
+
+impl<T: Numeric> DiagonalMatrix<T> {
+    /// Creates a new DiagonalMatrix with the given diagonal, whose length is
+    /// both the x and y length of the DiagonalMatrix.
+    pub fn new(diagonal: &[T]) -> Self {
+        Self {
+            diagonal: diagonal.to_vec(),
+        }
+    }
+
+    /// Creates a new DiagonalMatrix from the diagonal of any other Matrix,
+    /// returning `Error::NotSquare` if it is not square. The elements off the
+    /// diagonal are not read, so they are assumed to be zero.
+    pub fn from_dense<'a>(mat: &impl MatrixRef<'a, T>) -> Result<Self> {
+        check_square(mat)?;
+
+        Ok(Self {
+            diagonal: (0..mat.get_x_len())
+                .map(|i| mat.get_at_unchecked(i, i))
+                .collect(),
+        })
+    }
+
+    /// Copies the DiagonalMatrix into a new HeapMatrix.
+    pub fn to_heap_matrix(&self) -> HeapMatrix<T> {
+        let n = self.diagonal.len();
+        HeapMatrix::new(&self.to_vec(), n, n)
+    }
+
+    /// Gets the elements on the diagonal.
+    pub fn diagonal(&self) -> &[T] { &self.diagonal }
+}
+
+impl<T: Semiring> DiagonalMatrix<T> {
+    /// Creates a new `n` by `n` identity Matrix.
+    pub fn identity(n: usize) -> Self {
+        Self {
+            diagonal: vec![T::one(); n],
+        }
+    }
+
+    /// Returns the determinant of the DiagonalMatrix, which is the product of
+    /// its diagonal.
+    pub fn determinant(&self) -> T {
+        self.diagonal
+            .iter()
+            .fold(T::one(), |product, cell| product * *cell)
+    }
+
+    /// Multiplies the DiagonalMatrix by the vector `x` in O(n) time, returning
+    /// `Error::IncorrectLength` if `x` does not have as many elements as the
+    /// DiagonalMatrix has columns.
+    pub fn mul_vec(&self, x: &[T]) -> Result<Vec<T>> {
+        check_vec_len(self.diagonal.len(), x)?;
+
+        Ok(self
+            .diagonal
+            .iter()
+            .zip(x)
+            .map(|(cell, x)| *cell * *x)
+            .collect())
+    }
+
+    /// Computes the matrix product of the DiagonalMatrix and any other Matrix
+    /// by scaling its rows, returning `Error::DimensionMismatch` if `rhs` does
+    /// not have as many rows as the DiagonalMatrix has columns.
+    pub fn mul_dense<'a>(&self, rhs: &impl MatrixRef<'a, T>) -> Result<HeapMatrix<T>> {
+        map_columns(self.diagonal.len(), rhs, |column| self.mul_vec(column))
+    }
+}
+
+impl<T: Field> DiagonalMatrix<T> {
+    /// Solves `D * x = b` for `x` in O(n) time, returning
+    /// `Error::IncorrectLength` if `b` does not have the same length as the
+    /// diagonal or `Error::Singular` if the diagonal contains a zero.
+    pub fn solve_vec(&self, b: &[T]) -> Result<Vec<T>> {
+        check_vec_len(self.diagonal.len(), b)?;
+
+        self.diagonal
+            .iter()
+            .zip(b)
+            .map(|(cell, b)| {
+                if cell.is_zero() {
+                    Err(Error::Singular)
+                } else {
+                    Ok(*b / *cell)
+                }
+            })
+            .collect()
+    }
+
+    /// Solves `D * X = B` for `X`, treating each column of `B` as a separate
+    /// right-hand side. Returns `Error::DimensionMismatch` if `B` does not have
+    /// the same amount of rows as `D`, or `Error::Singular` if the diagonal
+    /// contains a zero.
+    pub fn solve<'a>(&self, b: &impl MatrixRef<'a, T>) -> Result<HeapMatrix<T>> {
+        map_columns(self.diagonal.len(), b, |column| self.solve_vec(column))
+    }
+
+    /// Returns the inverse of the DiagonalMatrix, or `Error::Singular` if the
+    /// diagonal contains a zero.
+    pub fn try_inverse(&self) -> Result<Self> {
+        Ok(Self {
+            diagonal: self.solve_vec(&vec![T::one(); self.diagonal.len()])?,
+        })
+    }
+}
+
+impl<T: Semiring> Add for DiagonalMatrix<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        check_same_dimensions(&self, &rhs).unwrap_or_else(|error| panic!("{}", error));
+
+        Self {
+            diagonal: self
+                .diagonal
+                .into_iter()
+                .zip(rhs.diagonal)
+                .map(|(l, r)| l + r)
+                .collect(),
+        }
+    }
+}
+
+impl<T: Ring> Sub for DiagonalMatrix<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        check_same_dimensions(&self, &rhs).unwrap_or_else(|error| panic!("{}", error));
+
+        Self {
+            diagonal: self
+                .diagonal
+                .into_iter()
+                .zip(rhs.diagonal)
+                .map(|(l, r)| l - r)
+                .collect(),
+        }
+    }
+}
+
+impl<T: Semiring> Mul for DiagonalMatrix<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            diagonal: self
+                .mul_vec(&rhs.diagonal)
+                .unwrap_or_else(|error| panic!("{}", error)),
+        }
+    }
+}
+
+impl_structured_ops!(DiagonalMatrix);
+
+impl<'a, T: 'a + Numeric> MatrixRef<'a, T> for DiagonalMatrix<T> {
+    fn get_x_len(&self) -> usize { self.diagonal.len() }
+    fn get_y_len(&self) -> usize { self.diagonal.len() }
+
+    fn get_at_unchecked(&self, x: usize, y: usize) -> T {
+        let n = self.diagonal.len();
+        assert!(x < n && y < n, "Index ({}, {}) is out of range", x, y);
+
+        if x == y {
+            self.diagonal[x]
+        } else {
+            T::zero()
+        }
+    }
+
+    fn to_vec(&self) -> Vec<T> {
+        let n = self.diagonal.len();
+        let mut data = vec![T::zero(); n * n];
+        for (i, cell) in self.diagonal.iter().enumerate() {
+            data[i * n + i] = *cell;
+        }
+
+        data
+    }
+}
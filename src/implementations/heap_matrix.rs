@@ -1,27 +1,55 @@
 use crate::{
-    common::Numeric,
+    common::{Numeric, Real, Ring, Semiring},
     error::{Error, Result},
     implementations::StackMatrix,
     matrix::*,
+    numbers::Complex,
 };
 use std::{
+    borrow::{Borrow, Cow},
+    cmp::Ordering,
     fmt::{Debug, Display, Formatter},
     marker::Sized,
     ops::*,
 };
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The order in which a HeapMatrix stores its elements.
+pub enum Layout {
+    /// Each row is stored contiguously, one after the other.
+    RowMajor,
+    /// Each column is stored contiguously, one after the other, as in Fortran.
+    ColumnMajor,
+}
+
+impl Default for Layout {
+    fn default() -> Self { Layout::RowMajor }
+}
+
+#[derive(Debug, Clone)]
 /// An implementation of the Matrix trait where the inner data is allocated on
-/// the heap.
+/// the heap. The data is stored in row-major order unless the HeapMatrix is
+/// created with or converted to another `Layout`. Every operation accepts
+/// either layout. The methods which modify or consume a HeapMatrix, such as
+/// `resize` and `*` by a scalar, keep its layout, as does `transpose`. Every
+/// other result, such as those of the arithmetic between matrices,
+/// concatenation and the decompositions, is row-major.
+///
+/// The lanes along the layout, rows when row-major and columns when
+/// column-major, are contiguous, so inserting or removing one only shifts the
+/// elements after it. Inserting or removing a lane across the layout touches
+/// every element, and changing the length of the contiguous lanes in `resize`
+/// rebuilds the data. Swapping lanes only touches the swapped elements.
 pub struct HeapMatrix<T: Numeric> {
     pub(crate) data: Vec<T>,
     pub(crate) x_len: usize,
     pub(crate) y_len: usize,
+    pub(crate) layout: Layout,
 }
 
-impl<'a, T: 'a + Numeric, Mat> Add<Mat> for HeapMatrix<T>
+impl<'a, T: 'a + Semiring, Mat> Add<Mat> for HeapMatrix<T>
 where
-    Mat: Matrix<'a, T> + Sized,
+    Mat: MatrixRef<'a, T> + Sized,
 {
     type Output = Self;
 
@@ -54,14 +82,13 @@ where
 //     fn add(self, rhs: &StackMatrix<T, X, Y>) -> Self::Output {
 // self.mat_add(rhs) } }
 
-impl<T: Numeric> Sub for HeapMatrix<T> {
+impl<T: Ring> Sub for HeapMatrix<T> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output { self.mat_sub(&rhs) }
 }
 
-impl<T: Numeric, const X: usize, const Y: usize> Sub<StackMatrix<T, X, Y>>
-    for HeapMatrix<T>
+impl<T: Ring, const X: usize, const Y: usize> Sub<StackMatrix<T, X, Y>> for HeapMatrix<T>
 where
     [T; X * Y]: Sized,
 {
@@ -70,14 +97,13 @@ where
     fn sub(self, rhs: StackMatrix<T, X, Y>) -> Self::Output { self.mat_sub(&rhs) }
 }
 
-impl<T: Numeric> Sub<&Self> for HeapMatrix<T> {
+impl<T: Ring> Sub<&Self> for HeapMatrix<T> {
     type Output = Self;
 
     fn sub(self, rhs: &Self) -> Self::Output { self.mat_sub(rhs) }
 }
 
-impl<T: Numeric, const X: usize, const Y: usize> Sub<&StackMatrix<T, X, Y>>
-    for HeapMatrix<T>
+impl<T: Ring, const X: usize, const Y: usize> Sub<&StackMatrix<T, X, Y>> for HeapMatrix<T>
 where
     [T; X * Y]: Sized,
 {
@@ -86,13 +112,13 @@ where
     fn sub(self, rhs: &StackMatrix<T, X, Y>) -> Self::Output { self.mat_sub(rhs) }
 }
 
-impl<T: Numeric> Mul for HeapMatrix<T> {
+impl<T: Semiring> Mul for HeapMatrix<T> {
     type Output = HeapMatrix<T>;
 
     fn mul(self, rhs: Self) -> Self::Output { self.mat_mul(&rhs) }
 }
 
-impl<T: Numeric, const X: usize, const Y: usize> Mul<StackMatrix<T, X, Y>>
+impl<T: Semiring, const X: usize, const Y: usize> Mul<StackMatrix<T, X, Y>>
     for HeapMatrix<T>
 where
     [T; X * Y]: Sized,
@@ -102,13 +128,13 @@ where
     fn mul(self, rhs: StackMatrix<T, X, Y>) -> Self::Output { self.mat_mul(&rhs) }
 }
 
-impl<T: Numeric> Mul<&Self> for HeapMatrix<T> {
+impl<T: Semiring> Mul<&Self> for HeapMatrix<T> {
     type Output = Self;
 
     fn mul(self, rhs: &Self) -> Self::Output { self.mat_mul(rhs) }
 }
 
-impl<T: Numeric, const X: usize, const Y: usize> Mul<&StackMatrix<T, X, Y>>
+impl<T: Semiring, const X: usize, const Y: usize> Mul<&StackMatrix<T, X, Y>>
     for HeapMatrix<T>
 where
     [T; X * Y]: Sized,
@@ -128,23 +154,23 @@ where
 //     }
 // }
 
-impl<T: Numeric> Sub<T> for HeapMatrix<T> {
+impl<T: Ring> Sub<T> for HeapMatrix<T> {
     type Output = Self;
 
-    fn sub(self, rhs: T) -> Self::Output {
-        let data: Vec<T> = self.data.iter().map(|x| *x - rhs).collect();
+    fn sub(mut self, rhs: T) -> Self::Output {
+        self.data.iter_mut().for_each(|x| *x -= rhs);
 
-        Self::new(&data, self.x_len, self.y_len)
+        self
     }
 }
 
-impl<T: Numeric> Mul<T> for HeapMatrix<T> {
+impl<T: Semiring> Mul<T> for HeapMatrix<T> {
     type Output = Self;
 
-    fn mul(self, rhs: T) -> Self::Output {
-        let data: Vec<T> = self.data.iter().map(|x| *x * rhs).collect();
+    fn mul(mut self, rhs: T) -> Self::Output {
+        self.data.iter_mut().for_each(|x| *x *= rhs);
 
-        Self::new(&data, self.x_len, self.y_len)
+        self
     }
 }
 
@@ -156,6 +182,18 @@ impl<T: Numeric> HeapMatrix<T> {
             data: data.to_vec(),
             x_len: columns,
             y_len: rows,
+            layout: Layout::RowMajor,
+        }
+    }
+
+    /// Takes a &[T] holding the elements column by column, as in Fortran, and
+    /// uses it as the inner data of a column-major HeapMatrix.
+    pub fn new_column_major(data: &[T], columns: usize, rows: usize) -> Self {
+        Self {
+            data: data.to_vec(),
+            x_len: columns,
+            y_len: rows,
+            layout: Layout::ColumnMajor,
         }
     }
 
@@ -166,7 +204,7 @@ impl<T: Numeric> HeapMatrix<T> {
         let y_len = data[0].len();
 
         let mut dat = Vec::new();
-        dat.resize(x_len * y_len, T::default());
+        dat.resize(x_len * y_len, T::zero());
 
         for i in 0..dat.len() {
             dat[i] = data[i / x_len][i % x_len];
@@ -176,6 +214,7 @@ impl<T: Numeric> HeapMatrix<T> {
             data: dat,
             x_len,
             y_len,
+            layout: Layout::RowMajor,
         }
     }
 
@@ -196,6 +235,7 @@ impl<T: Numeric> HeapMatrix<T> {
             data: dat.to_vec(),
             x_len: X,
             y_len: Y,
+            layout: Layout::RowMajor,
         }
     }
 
@@ -206,25 +246,406 @@ impl<T: Numeric> HeapMatrix<T> {
             data: data.to_vec(),
             x_len: X,
             y_len: Y,
+            layout: Layout::RowMajor,
+        }
+    }
+
+    /// Gets the order in which the HeapMatrix stores its elements.
+    pub fn layout(&self) -> Layout { self.layout }
+
+    /// Returns a copy of the HeapMatrix which stores its elements in the given
+    /// layout.
+    pub fn to_layout(&self, layout: Layout) -> Self {
+        let mut mat = self.clone();
+        mat.set_layout(layout);
+
+        mat
+    }
+
+    /// Rearranges the inner data in place so that the HeapMatrix stores its
+    /// elements in the given layout. This does nothing if the HeapMatrix
+    /// already uses that layout.
+    pub fn set_layout(&mut self, layout: Layout) {
+        if layout != self.layout {
+            let (columns, rows) = self.buffer_dimensions();
+            transpose_in_place(&mut self.data, columns, rows);
+            self.layout = layout;
+        }
+    }
+
+    /// Gets the elements in row-major order, only copying them if the
+    /// HeapMatrix is column-major.
+    pub(crate) fn row_major_data(&self) -> Cow<'_, [T]> {
+        match self.layout {
+            Layout::RowMajor => Cow::Borrowed(&self.data),
+            Layout::ColumnMajor => Cow::Owned(strided_to_vec(self)),
+        }
+    }
+
+    fn strides(&self) -> (usize, usize) {
+        match self.layout {
+            Layout::RowMajor => (1, self.x_len),
+            Layout::ColumnMajor => (self.y_len, 1),
+        }
+    }
+
+    /// Gets the dimensions of the inner data when it is read as a row-major
+    /// buffer, which are swapped for a column-major HeapMatrix.
+    fn buffer_dimensions(&self) -> (usize, usize) {
+        match self.layout {
+            Layout::RowMajor => (self.x_len, self.y_len),
+            Layout::ColumnMajor => (self.y_len, self.x_len),
+        }
+    }
+
+    /// Builds the inner data of a `columns x rows` HeapMatrix with the same
+    /// layout, whose element at (x, y) is `f(x, y)`.
+    fn rebuilt(
+        &self,
+        columns: usize,
+        rows: usize,
+        mut f: impl FnMut(usize, usize) -> T,
+    ) -> Vec<T> {
+        let mut data = Vec::with_capacity(columns * rows);
+        match self.layout {
+            Layout::RowMajor => {
+                (0..rows).for_each(|y| (0..columns).for_each(|x| data.push(f(x, y))))
+            }
+            Layout::ColumnMajor => {
+                (0..columns).for_each(|x| (0..rows).for_each(|y| data.push(f(x, y))))
+            }
         }
+
+        data
+    }
+
+    /// Removes every element whose position in the inner data leaves `index`
+    /// as the remainder when divided by `lane_len`, which removes a row of a
+    /// column-major HeapMatrix or a column of a row-major one.
+    fn remove_interleaved(&mut self, lane_len: usize, index: usize) {
+        let mut position = 0;
+        self.data.retain(|_| {
+            position += 1;
+            (position - 1) % lane_len != index
+        });
     }
 
     /// Takes in a new row as a [T; X] and inserts it into the current
     /// HeapMatrix.
     pub fn insert_row<const X: usize>(&mut self, row: [T; X]) -> Result<()> {
-        if row.len() != self.x_len {
-            return Err(Error::IncorrectLength);
-        }
+        self.insert_row_at(self.y_len, row)
+    }
 
-        self.data.resize(self.data.len() + X, T::default());
+    /// Inserts a row at the given y index, shifting the rows below it down.
+    /// Returns `Error::OutOfRange` if the index is greater than the y length,
+    /// or `Error::IncorrectLength` if the row does not have as many elements
+    /// as the HeapMatrix has columns. A HeapMatrix without rows takes its x
+    /// length from the first row inserted into it.
+    pub fn insert_row_at<I>(&mut self, index: usize, row: I) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<T>,
+    {
+        if index > self.y_len {
+            return Err(Error::OutOfRange {
+                index: (0, index),
+                bounds: (self.x_len, self.y_len),
+            });
+        }
 
-        for (column_index, x) in row.iter().enumerate() {
-            self.data[self.x_len * self.y_len + column_index] = *x;
+        let row: Vec<T> = row.into_iter().map(|x| *x.borrow()).collect();
+        if self.y_len == 0 {
+            self.x_len = row.len();
+        } else if row.len() != self.x_len {
+            return Err(Error::IncorrectLength {
+                expected: self.x_len,
+                found: row.len(),
+            });
         }
 
+        match self.layout {
+            Layout::RowMajor => {
+                let start = index * self.x_len;
+                self.data.splice(start..start, row);
+            }
+            Layout::ColumnMajor => {
+                self.data = self.rebuilt(self.x_len, self.y_len + 1, |x, y| {
+                    match y.cmp(&index) {
+                        Ordering::Less => self.get_at_unchecked(x, y),
+                        Ordering::Equal => row[x],
+                        Ordering::Greater => self.get_at_unchecked(x, y - 1),
+                    }
+                });
+            }
+        }
         self.y_len += 1;
+
+        Ok(())
+    }
+
+    /// Inserts a column at the given x index, shifting the columns to the right
+    /// of it over. Returns `Error::OutOfRange` if the index is greater than the
+    /// x length, or `Error::IncorrectLength` if the column does not have as
+    /// many elements as the HeapMatrix has rows. A HeapMatrix without columns
+    /// takes its y length from the first column inserted into it.
+    pub fn insert_column<I>(&mut self, index: usize, column: I) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<T>,
+    {
+        if index > self.x_len {
+            return Err(Error::OutOfRange {
+                index: (index, 0),
+                bounds: (self.x_len, self.y_len),
+            });
+        }
+
+        let column: Vec<T> = column.into_iter().map(|x| *x.borrow()).collect();
+        if self.x_len == 0 {
+            self.y_len = column.len();
+        } else if column.len() != self.y_len {
+            return Err(Error::IncorrectLength {
+                expected: self.y_len,
+                found: column.len(),
+            });
+        }
+
+        match self.layout {
+            Layout::RowMajor => {
+                self.data = self.rebuilt(self.x_len + 1, self.y_len, |x, y| {
+                    match x.cmp(&index) {
+                        Ordering::Less => self.get_at_unchecked(x, y),
+                        Ordering::Equal => column[y],
+                        Ordering::Greater => self.get_at_unchecked(x - 1, y),
+                    }
+                });
+            }
+            Layout::ColumnMajor => {
+                let start = index * self.y_len;
+                self.data.splice(start..start, column);
+            }
+        }
+        self.x_len += 1;
+
+        Ok(())
+    }
+
+    /// Removes the row at the given y index and returns it, shifting the rows
+    /// below it up. Returns `Error::OutOfRange` if there is no such row.
+    pub fn remove_row(&mut self, index: usize) -> Result<Vec<T>> {
+        if index >= self.y_len {
+            return Err(Error::OutOfRange {
+                index: (0, index),
+                bounds: (self.x_len, self.y_len),
+            });
+        }
+
+        let row = self.row(index).copied().collect();
+        match self.layout {
+            Layout::RowMajor => {
+                let start = index * self.x_len;
+                self.data.drain(start..start + self.x_len);
+            }
+            Layout::ColumnMajor => self.remove_interleaved(self.y_len, index),
+        }
+        self.y_len -= 1;
+
+        Ok(row)
+    }
+
+    /// Removes the column at the given x index and returns it, shifting the
+    /// columns to the right of it over. Returns `Error::OutOfRange` if there
+    /// is no such column.
+    pub fn remove_column(&mut self, index: usize) -> Result<Vec<T>> {
+        if index >= self.x_len {
+            return Err(Error::OutOfRange {
+                index: (index, 0),
+                bounds: (self.x_len, self.y_len),
+            });
+        }
+
+        let column = self.column(index).copied().collect();
+        match self.layout {
+            Layout::RowMajor => self.remove_interleaved(self.x_len, index),
+            Layout::ColumnMajor => {
+                let start = index * self.y_len;
+                self.data.drain(start..start + self.y_len);
+            }
+        }
+        self.x_len -= 1;
+
+        Ok(column)
+    }
+
+    /// Swaps the rows at the y indices `a` and `b`, returning
+    /// `Error::OutOfRange` if either row does not exist.
+    pub fn swap_rows(&mut self, a: usize, b: usize) -> Result<()> {
+        if a.max(b) >= self.y_len {
+            return Err(Error::OutOfRange {
+                index: (0, a.max(b)),
+                bounds: (self.x_len, self.y_len),
+            });
+        }
+
+        let (x_stride, y_stride) = self.strides();
+        if a != b {
+            for x in 0..self.x_len {
+                self.data
+                    .swap(x * x_stride + a * y_stride, x * x_stride + b * y_stride);
+            }
+        }
+
         Ok(())
     }
+
+    /// Swaps the columns at the x indices `a` and `b`, returning
+    /// `Error::OutOfRange` if either column does not exist.
+    pub fn swap_columns(&mut self, a: usize, b: usize) -> Result<()> {
+        if a.max(b) >= self.x_len {
+            return Err(Error::OutOfRange {
+                index: (a.max(b), 0),
+                bounds: (self.x_len, self.y_len),
+            });
+        }
+
+        let (x_stride, y_stride) = self.strides();
+        if a != b {
+            for y in 0..self.y_len {
+                self.data
+                    .swap(a * x_stride + y * y_stride, b * x_stride + y * y_stride);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Shrinks the HeapMatrix to its top-left `columns x rows` block. Lengths
+    /// which are already smaller than the ones given are left unchanged.
+    pub fn truncate(&mut self, columns: usize, rows: usize) {
+        let columns = columns.min(self.x_len);
+        let rows = rows.min(self.y_len);
+
+        self.resize(columns, rows, T::zero());
+    }
+
+    /// Resizes the HeapMatrix to `columns x rows`, keeping the elements in
+    /// the top-left block which both sizes share and filling any new elements
+    /// with `fill`.
+    pub fn resize(&mut self, columns: usize, rows: usize, fill: T) {
+        // Only the last rows of a row-major HeapMatrix, or the last columns of a
+        // column-major one, change when the length of its lanes is kept.
+        let lane_len_kept = match self.layout {
+            Layout::RowMajor => columns == self.x_len,
+            Layout::ColumnMajor => rows == self.y_len,
+        };
+
+        if lane_len_kept {
+            self.data.resize(columns * rows, fill);
+        } else {
+            self.data = self.rebuilt(columns, rows, |x, y| {
+                if x < self.x_len && y < self.y_len {
+                    self.get_at_unchecked(x, y)
+                } else {
+                    fill
+                }
+            });
+        }
+        self.x_len = columns;
+        self.y_len = rows;
+    }
+
+    /// Assembles a HeapMatrix from a grid of blocks, where `blocks[i][j]` is
+    /// the block in the i-th row and j-th column of the grid. Every block in
+    /// a row of the grid must have the same y length and every block in a
+    /// column of the grid the same x length, otherwise
+    /// `Error::DimensionMismatch` is returned. Every row of the grid must also
+    /// have the same amount of blocks, otherwise `Error::IncorrectLength` is
+    /// returned.
+    pub fn block<'a>(blocks: &[&[&dyn MatrixRef<'a, T>]]) -> Result<Self> {
+        let grid_columns = blocks.first().map_or(0, |row| row.len());
+        let widths: Vec<usize> = match blocks.first() {
+            Some(row) => row.iter().map(|block| block.get_x_len()).collect(),
+            None => Vec::new(),
+        };
+        let columns: usize = widths.iter().sum();
+
+        let mut data = Vec::new();
+        let mut rows = 0;
+        for row in blocks {
+            if row.len() != grid_columns {
+                return Err(Error::IncorrectLength {
+                    expected: grid_columns,
+                    found: row.len(),
+                });
+            }
+
+            let height = row.first().map_or(0, |block| block.get_y_len());
+            for (block, width) in row.iter().zip(&widths) {
+                if (block.get_x_len(), block.get_y_len()) != (*width, height) {
+                    return Err(Error::DimensionMismatch {
+                        expected: (*width, height),
+                        found: (block.get_x_len(), block.get_y_len()),
+                    });
+                }
+            }
+
+            for y in 0..height {
+                for block in row.iter() {
+                    data.extend(
+                        (0..block.get_x_len()).map(|x| block.get_at_unchecked(x, y)),
+                    );
+                }
+            }
+            rows += height;
+        }
+
+        Ok(Self::new(&data, columns, rows))
+    }
+
+    /// Returns the transpose of the HeapMatrix, which has the x and y lengths
+    /// swapped.
+    pub fn transpose(&self) -> Self {
+        let (columns, rows) = self.buffer_dimensions();
+        let mut data = vec![T::zero(); self.data.len()];
+        transpose_into(&self.data, &mut data, columns, rows);
+
+        Self {
+            data,
+            x_len: self.y_len,
+            y_len: self.x_len,
+            layout: self.layout,
+        }
+    }
+
+    /// Transposes the HeapMatrix in place without allocating a second buffer
+    /// for the data. Square matrices are transposed block by block to stay
+    /// cache-friendly, while rectangular ones have their elements permuted in
+    /// cycles.
+    pub fn transpose_mut(&mut self) {
+        let (columns, rows) = self.buffer_dimensions();
+        transpose_in_place(&mut self.data, columns, rows);
+        std::mem::swap(&mut self.x_len, &mut self.y_len);
+    }
+}
+
+impl<'a, T: Numeric, Mat: MatrixRef<'a, T>> HStack<Mat> for HeapMatrix<T> {
+    type Output = Self;
+
+    fn hstack(&self, rhs: &Mat) -> Result<Self::Output> {
+        let data = hstack_data(self, rhs)?;
+
+        Ok(Self::new(&data, self.x_len + rhs.get_x_len(), self.y_len))
+    }
+}
+
+impl<'a, T: Numeric, Mat: MatrixRef<'a, T>> VStack<Mat> for HeapMatrix<T> {
+    type Output = Self;
+
+    fn vstack(&self, rhs: &Mat) -> Result<Self::Output> {
+        let data = vstack_data(self, rhs)?;
+
+        Ok(Self::new(&data, self.x_len, self.y_len + rhs.get_y_len()))
+    }
 }
 
 impl<T: Numeric, const X: usize, const Y: usize> PartialEq<StackMatrix<T, X, Y>>
@@ -237,11 +658,25 @@ where
             return false;
         }
 
-        self.data == other.data
+        *self.row_major_data() == other.data[..]
+    }
+}
+
+impl<T: Numeric> PartialEq for HeapMatrix<T> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.x_len != other.x_len || self.y_len != other.y_len {
+            return false;
+        }
+
+        if self.layout == other.layout {
+            self.data == other.data
+        } else {
+            self.row_major_data() == other.row_major_data()
+        }
     }
 }
 
-impl<T: Numeric> Display for HeapMatrix<T> {
+impl<T: Numeric + Display> Display for HeapMatrix<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.to_printable())
     }
@@ -262,19 +697,39 @@ impl<'a, T: 'a + Numeric> MatrixAlloc<'a, T> for HeapMatrix<T> {
 }
 
 impl<'a, T: 'a + Numeric> MatrixRef<'a, T> for HeapMatrix<T> {
-    fn get_data(&self) -> &[T] { &self.data }
     fn get_x_len(&self) -> usize { self.x_len }
     fn get_y_len(&self) -> usize { self.y_len }
+    fn get_at_unchecked(&self, x: usize, y: usize) -> T { get_strided(self, x, y) }
+    fn to_vec(&self) -> Vec<T> { self.row_major_data().into_owned() }
 }
 
-impl<'a, T: 'a + Numeric> MatrixRef<'a, T> for &HeapMatrix<T> {
+impl<'a, T: 'a + Numeric> DenseMatrixRef<'a, T> for HeapMatrix<T> {
     fn get_data(&self) -> &[T] { &self.data }
+
+    fn get_strides(&self) -> (usize, usize) { self.strides() }
+}
+
+impl<'a, T: 'a + Numeric> MatrixRef<'a, T> for &HeapMatrix<T> {
     fn get_x_len(&self) -> usize { self.x_len }
     fn get_y_len(&self) -> usize { self.y_len }
+    fn get_at_unchecked(&self, x: usize, y: usize) -> T { get_strided(self, x, y) }
+    fn to_vec(&self) -> Vec<T> { self.row_major_data().into_owned() }
+}
+
+impl<'a, T: 'a + Numeric> DenseMatrixRef<'a, T> for &HeapMatrix<T> {
+    fn get_data(&self) -> &[T] { &self.data }
+
+    fn get_strides(&self) -> (usize, usize) { self.strides() }
 }
 
 impl<'a, T: 'a + Numeric> Matrix<'a, T> for HeapMatrix<T> {
     fn get_data_mut(&mut self) -> &mut [T] { &mut self.data }
 }
 
-impl<'a, T: 'a + Numeric> MatrixOp<'a, T> for HeapMatrix<T> {}
+impl<'a, T: 'a + Semiring> MatrixOp<'a, T> for HeapMatrix<T> {}
+
+impl<'a, T: 'a + Semiring> SquareMatrix<'a, T> for HeapMatrix<T> {}
+
+impl<'a, T: 'a + Real> RealMatrix<'a, T> for HeapMatrix<T> {}
+
+impl<'a, T: 'a + Ring> ComplexMatrix<'a, T> for HeapMatrix<Complex<T>> {}
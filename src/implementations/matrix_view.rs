@@ -0,0 +1,176 @@
+use crate::{
+    common::Numeric, implementations::HeapMatrix, iterators::lanes_separate, matrix::*,
+};
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Copy, Clone)]
+/// A borrowed, read-only view of part of a Matrix, which is created with
+/// [`MatrixRef::view`] and related methods. The elements of the view are found
+/// in the borrowed data using an x and a y stride, so views can be taken of
+/// blocks, columns, diagonals and transposes without copying.
+pub struct MatrixView<'a, T: Numeric> {
+    data: &'a [T],
+    x_len: usize,
+    y_len: usize,
+    x_stride: usize,
+    y_stride: usize,
+}
+
+#[derive(Debug)]
+/// A mutably borrowed view of part of a Matrix, which is created with
+/// [`Matrix::view_mut`] and related methods. Writing to the view writes to the
+/// Matrix it was created from.
+pub struct MatrixViewMut<'a, T: Numeric> {
+    data: &'a mut [T],
+    x_len: usize,
+    y_len: usize,
+    x_stride: usize,
+    y_stride: usize,
+}
+
+/// Panics if the element at (columns - 1, rows - 1) would be outside of `len`
+/// elements of data.
+fn check_bounds(
+    len: usize,
+    columns: usize,
+    rows: usize,
+    x_stride: usize,
+    y_stride: usize,
+) {
+    if columns > 0 && rows > 0 {
+        assert!(
+            (columns - 1) * x_stride + (rows - 1) * y_stride < len,
+            "The view does not fit in the data"
+        );
+    }
+}
+
+impl<'a, T: Numeric> MatrixView<'a, T> {
+    /// Creates a view of `columns x rows` elements of `data`, where the element
+    /// at (x, y) is `data[x * x_stride + y * y_stride]`. Panics if the last
+    /// element is outside of `data`.
+    pub fn new(
+        data: &'a [T],
+        columns: usize,
+        rows: usize,
+        x_stride: usize,
+        y_stride: usize,
+    ) -> Self {
+        check_bounds(data.len(), columns, rows, x_stride, y_stride);
+
+        Self {
+            data,
+            x_len: columns,
+            y_len: rows,
+            x_stride,
+            y_stride,
+        }
+    }
+
+    /// Copies the elements of the view into a new HeapMatrix.
+    pub fn to_heap_matrix(&self) -> HeapMatrix<T> {
+        HeapMatrix::new(&self.to_vec(), self.x_len, self.y_len)
+    }
+}
+
+impl<'a, T: Numeric> MatrixViewMut<'a, T> {
+    /// Creates a mutable view of `columns x rows` elements of `data`, where
+    /// the element at (x, y) is `data[x * x_stride + y * y_stride]`. Panics if
+    /// the last element is outside of `data`, or unless either the rows or the
+    /// columns of the view lie in separate ranges of `data`, which ensures that
+    /// no two elements share the same index.
+    pub fn new(
+        data: &'a mut [T],
+        columns: usize,
+        rows: usize,
+        x_stride: usize,
+        y_stride: usize,
+    ) -> Self {
+        check_bounds(data.len(), columns, rows, x_stride, y_stride);
+        assert!(
+            lanes_separate(rows, columns, y_stride, x_stride)
+                || lanes_separate(columns, rows, x_stride, y_stride),
+            "The elements of a mutable view must not overlap"
+        );
+
+        Self {
+            data,
+            x_len: columns,
+            y_len: rows,
+            x_stride,
+            y_stride,
+        }
+    }
+
+    /// Reborrows the mutable view as a read-only view.
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView::new(
+            self.data,
+            self.x_len,
+            self.y_len,
+            self.x_stride,
+            self.y_stride,
+        )
+    }
+
+    /// Copies the elements of the view into a new HeapMatrix.
+    pub fn to_heap_matrix(&self) -> HeapMatrix<T> {
+        HeapMatrix::new(&self.to_vec(), self.x_len, self.y_len)
+    }
+}
+
+impl<'a, 'b, T: Numeric> PartialEq<MatrixView<'b, T>> for MatrixView<'a, T> {
+    fn eq(&self, other: &MatrixView<'b, T>) -> bool {
+        self.x_len == other.x_len
+            && self.y_len == other.y_len
+            && self.to_vec() == other.to_vec()
+    }
+}
+
+impl<'a, T: Numeric> PartialEq<HeapMatrix<T>> for MatrixView<'a, T> {
+    fn eq(&self, other: &HeapMatrix<T>) -> bool {
+        self.x_len == other.x_len
+            && self.y_len == other.y_len
+            && self.to_vec() == *other.row_major_data()
+    }
+}
+
+impl<'a, T: Numeric + Display> Display for MatrixView<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_printable())
+    }
+}
+
+impl<'a, T: Numeric + Display> Display for MatrixViewMut<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_printable())
+    }
+}
+
+impl<'a, 'v, T: 'a + Numeric> MatrixRef<'a, T> for MatrixView<'v, T> {
+    fn get_x_len(&self) -> usize { self.x_len }
+    fn get_y_len(&self) -> usize { self.y_len }
+    fn get_at_unchecked(&self, x: usize, y: usize) -> T { get_strided(self, x, y) }
+    fn to_vec(&self) -> Vec<T> { strided_to_vec(self) }
+}
+
+impl<'a, 'v, T: 'a + Numeric> DenseMatrixRef<'a, T> for MatrixView<'v, T> {
+    fn get_data(&self) -> &[T] { self.data }
+    fn get_strides(&self) -> (usize, usize) { (self.x_stride, self.y_stride) }
+}
+
+impl<'a, 'v, T: 'a + Numeric> MatrixRef<'a, T> for MatrixViewMut<'v, T> {
+    fn get_x_len(&self) -> usize { self.x_len }
+    fn get_y_len(&self) -> usize { self.y_len }
+    fn get_at_unchecked(&self, x: usize, y: usize) -> T { get_strided(self, x, y) }
+    fn to_vec(&self) -> Vec<T> { strided_to_vec(self) }
+}
+
+impl<'a, 'v, T: 'a + Numeric> DenseMatrixRef<'a, T> for MatrixViewMut<'v, T> {
+    fn get_data(&self) -> &[T] { self.data }
+    fn get_strides(&self) -> (usize, usize) { (self.x_stride, self.y_stride) }
+}
+
+impl<'a, 'v, T: 'a + Numeric> Matrix<'a, T> for MatrixViewMut<'v, T> {
+    fn get_data_mut(&mut self) -> &mut [T] { self.data }
+}
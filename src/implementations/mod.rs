@@ -1,5 +1,96 @@
+/// Implements the operators between a square structured Matrix type and
+/// `HeapMatrix`, along with equality with `HeapMatrix`, `Display` and
+/// `LinearOperator`. The type must implement `MatrixRef`, `to_heap_matrix`,
+/// `mul_vec` and `mul_dense`.
+macro_rules! impl_structured_ops {
+    ($($t:ident),*) => {$(
+        impl<T: $crate::common::Semiring> Add<HeapMatrix<T>> for $t<T> {
+            type Output = HeapMatrix<T>;
+
+            fn add(self, rhs: HeapMatrix<T>) -> Self::Output { rhs.mat_add(&self) }
+        }
+
+        impl<T: $crate::common::Ring> Sub<HeapMatrix<T>> for $t<T> {
+            type Output = HeapMatrix<T>;
+
+            fn sub(self, rhs: HeapMatrix<T>) -> Self::Output {
+                self.to_heap_matrix().mat_sub(&rhs)
+            }
+        }
+
+        impl<T: $crate::common::Ring> Sub<$t<T>> for HeapMatrix<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: $t<T>) -> Self::Output { self.mat_sub(&rhs) }
+        }
+
+        impl<T: $crate::common::Semiring> Mul<HeapMatrix<T>> for $t<T> {
+            type Output = HeapMatrix<T>;
+
+            fn mul(self, rhs: HeapMatrix<T>) -> Self::Output { &self * &rhs }
+        }
+
+        impl<T: $crate::common::Semiring> Mul<&HeapMatrix<T>> for &$t<T> {
+            type Output = HeapMatrix<T>;
+
+            fn mul(self, rhs: &HeapMatrix<T>) -> Self::Output {
+                self.mul_dense(rhs)
+                    .unwrap_or_else(|error| panic!("{}", error))
+            }
+        }
+
+        impl<T: $crate::common::Semiring> Mul<$t<T>> for HeapMatrix<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: $t<T>) -> Self::Output { self.mat_mul(&rhs) }
+        }
+
+        impl<T: Numeric> PartialEq<HeapMatrix<T>> for $t<T> {
+            fn eq(&self, other: &HeapMatrix<T>) -> bool {
+                self.get_x_len() == other.x_len
+                    && self.get_y_len() == other.y_len
+                    && self.to_vec() == *other.row_major_data()
+            }
+        }
+
+        impl<T: Numeric> PartialEq<$t<T>> for HeapMatrix<T> {
+            fn eq(&self, other: &$t<T>) -> bool { other == self }
+        }
+
+        impl<T: Numeric + Display> Display for $t<T> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.to_printable())
+            }
+        }
+
+        impl<'a, T: 'a + $crate::common::Semiring> $crate::solvers::LinearOperator<'a, T>
+            for $t<T>
+        {
+            fn apply(&self, x: &[T]) -> Vec<T> {
+                self.mul_vec(x).unwrap_or_else(|error| panic!("{}", error))
+            }
+        }
+    )*};
+}
+
+pub mod banded_matrix;
+pub mod coo_matrix;
+pub mod csc_matrix;
+pub mod diagonal_matrix;
 pub mod heap_matrix;
+pub mod matrix_view;
+pub mod sparse_matrix;
 pub mod stack_matrix;
+pub mod symmetric_matrix;
+pub mod triangular_matrix;
 
+pub use banded_matrix::*;
+pub use coo_matrix::*;
+pub use csc_matrix::*;
+pub use diagonal_matrix::*;
 pub use heap_matrix::*;
+pub use matrix_view::*;
+pub use sparse_matrix::*;
 pub use stack_matrix::*;
+pub use symmetric_matrix::*;
+pub use triangular_matrix::*;
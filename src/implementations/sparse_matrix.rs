@@ -0,0 +1,380 @@
+use crate::{
+    common::{Numeric, Semiring},
+    error::{Error, Result},
+    implementations::{HeapMatrix, StackMatrix},
+    matrix::*,
+};
+use std::{
+    fmt::{Display, Formatter},
+    ops::*,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+/// A Matrix stored in compressed sparse row (CSR) form, which only stores its
+/// non-zero elements. The elements of row `y` are `values[row_offsets[y]..
+/// row_offsets[y + 1]]`, and the columns they are in are the same range of
+/// `column_indices`.
+///
+/// The column indices within each row are kept sorted and zeros are never
+/// stored, so two SparseMatrices are equal exactly when their elements are.
+pub struct SparseMatrix<T: Numeric> {
+    pub(crate) row_offsets: Vec<usize>,
+    pub(crate) column_indices: Vec<usize>,
+    pub(crate) values: Vec<T>,
+    pub(crate) x_len: usize,
+    pub(crate) y_len: usize,
+}
+
+impl<T: Numeric> SparseMatrix<T> {
+    /// Creates a new SparseMatrix with the given dimensions where every element
+    /// is zero.
+    pub fn new(columns: usize, rows: usize) -> Self {
+        Self {
+            row_offsets: vec![0; rows + 1],
+            column_indices: Vec::new(),
+            values: Vec::new(),
+            x_len: columns,
+            y_len: rows,
+        }
+    }
+
+    /// Creates a new SparseMatrix holding the non-zero elements of any other
+    /// Matrix.
+    pub fn from_dense<'a>(mat: &impl MatrixRef<'a, T>) -> Self {
+        let mut sparse = Self::new(mat.get_x_len(), mat.get_y_len());
+
+        for y in 0..mat.get_y_len() {
+            for x in 0..mat.get_x_len() {
+                let cell = mat.get_at_unchecked(x, y);
+                if !cell.is_zero() {
+                    sparse.column_indices.push(x);
+                    sparse.values.push(cell);
+                }
+            }
+            sparse.row_offsets[y + 1] = sparse.values.len();
+        }
+
+        sparse
+    }
+
+    /// Copies the SparseMatrix into a new HeapMatrix.
+    pub fn to_heap_matrix(&self) -> HeapMatrix<T> {
+        HeapMatrix::new(&self.to_vec(), self.x_len, self.y_len)
+    }
+
+    /// Returns the amount of stored, non-zero elements.
+    pub fn nnz(&self) -> usize { self.values.len() }
+
+    /// Gets the offsets into the column indices and values at which each row
+    /// starts, followed by the amount of stored elements.
+    pub fn row_offsets(&self) -> &[usize] { &self.row_offsets }
+
+    /// Gets the column index of each stored element.
+    pub fn column_indices(&self) -> &[usize] { &self.column_indices }
+
+    /// Gets the value of each stored element.
+    pub fn values(&self) -> &[T] { &self.values }
+
+    /// Gets the column indices and values of the stored elements in the row at
+    /// the given y index, panicking if there is no such row.
+    pub fn row_entries(&self, y: usize) -> (&[usize], &[T]) {
+        assert!(y < self.y_len, "Row {} is out of range", y);
+        let range = self.row_offsets[y]..self.row_offsets[y + 1];
+
+        (&self.column_indices[range.clone()], &self.values[range])
+    }
+}
+
+impl<T: Semiring> SparseMatrix<T> {
+    /// Creates a new SparseMatrix from its CSR arrays, where `row_offsets` has
+    /// one more element than there are rows. The entries of each row may be in
+    /// any order; entries in the same position are summed and zeros are
+    /// dropped. Returns `Error::IncorrectLength` if the lengths of the arrays
+    /// are inconsistent, `Error::InvalidOffset` if the offsets do not start at
+    /// zero or decrease, or `Error::OutOfRange` if a column index is not less
+    /// than `columns`.
+    pub fn from_csr(
+        columns: usize,
+        rows: usize,
+        row_offsets: Vec<usize>,
+        column_indices: Vec<usize>,
+        values: Vec<T>,
+    ) -> Result<Self> {
+        if row_offsets.len() != rows + 1 {
+            return Err(Error::IncorrectLength {
+                expected: rows + 1,
+                found: row_offsets.len(),
+            });
+        }
+        if column_indices.len() != values.len() {
+            return Err(Error::IncorrectLength {
+                expected: values.len(),
+                found: column_indices.len(),
+            });
+        }
+        check_offsets(&row_offsets, values.len())?;
+
+        let mut sparse = Self::new(columns, rows);
+        for y in 0..rows {
+            let range = row_offsets[y]..row_offsets[y + 1];
+            let mut row: Vec<(usize, T)> = column_indices[range.clone()]
+                .iter()
+                .copied()
+                .zip(values[range].iter().copied())
+                .collect();
+
+            if let Some((x, _)) = row.iter().find(|(x, _)| *x >= columns) {
+                return Err(Error::OutOfRange {
+                    index: (*x, y),
+                    bounds: (columns, rows),
+                });
+            }
+
+            row.sort_by_key(|(x, _)| *x);
+            sparse.push_row(y, row);
+        }
+
+        Ok(sparse)
+    }
+
+    /// Fills in the row at the given y index from entries sorted by column,
+    /// summing entries in the same column and dropping zeros. Rows must be
+    /// filled in order, starting from the first.
+    pub(crate) fn push_row(
+        &mut self,
+        y: usize,
+        row: impl IntoIterator<Item = (usize, T)>,
+    ) {
+        push_lane(&mut self.column_indices, &mut self.values, row);
+        self.row_offsets[y + 1] = self.values.len();
+    }
+
+    /// Multiplies the SparseMatrix by the vector `x`, returning
+    /// `Error::IncorrectLength` if `x` does not have as many elements as the
+    /// SparseMatrix has columns.
+    pub fn mul_vec(&self, x: &[T]) -> Result<Vec<T>> {
+        if x.len() != self.x_len {
+            return Err(Error::IncorrectLength {
+                expected: self.x_len,
+                found: x.len(),
+            });
+        }
+
+        Ok((0..self.y_len)
+            .map(|y| {
+                let (columns, values) = self.row_entries(y);
+                columns
+                    .iter()
+                    .zip(values)
+                    .fold(T::zero(), |sum, (column, value)| sum + *value * x[*column])
+            })
+            .collect())
+    }
+
+    /// Computes the matrix product of the SparseMatrix and any dense or sparse
+    /// Matrix, returning `Error::DimensionMismatch` if the x length of `self`
+    /// is not equal to the y length of `rhs`.
+    pub fn mul_dense<'a>(&self, rhs: &impl MatrixRef<'a, T>) -> Result<HeapMatrix<T>> {
+        check_product_dimensions(self, rhs)?;
+
+        let columns = rhs.get_x_len();
+        let rhs = rhs.to_vec();
+        let mut data = vec![T::zero(); columns * self.y_len];
+        for y in 0..self.y_len {
+            let (indices, values) = self.row_entries(y);
+            for (k, value) in indices.iter().zip(values) {
+                for x in 0..columns {
+                    data[y * columns + x] += *value * rhs[k * columns + x];
+                }
+            }
+        }
+
+        Ok(HeapMatrix::new(&data, columns, self.y_len))
+    }
+
+    /// Computes the matrix product of two SparseMatrices without converting
+    /// either to a dense Matrix, returning `Error::DimensionMismatch` if the x
+    /// length of `self` is not equal to the y length of `rhs`.
+    pub fn mul_sparse(&self, rhs: &SparseMatrix<T>) -> Result<SparseMatrix<T>> {
+        check_product_dimensions(self, rhs)?;
+
+        let mut product = Self::new(rhs.x_len, self.y_len);
+        // A dense accumulator for the current row, with the columns that have
+        // been written to so far.
+        let mut accumulator = vec![T::zero(); rhs.x_len];
+        let mut occupied = vec![false; rhs.x_len];
+        let mut touched = Vec::new();
+
+        for y in 0..self.y_len {
+            let (indices, values) = self.row_entries(y);
+            for (k, value) in indices.iter().zip(values) {
+                let (rhs_indices, rhs_values) = rhs.row_entries(*k);
+                for (x, rhs_value) in rhs_indices.iter().zip(rhs_values) {
+                    if !occupied[*x] {
+                        occupied[*x] = true;
+                        touched.push(*x);
+                    }
+                    accumulator[*x] += *value * *rhs_value;
+                }
+            }
+
+            touched.sort_unstable();
+            product.push_row(y, touched.iter().map(|x| (*x, accumulator[*x])));
+            for x in touched.drain(..) {
+                accumulator[x] = T::zero();
+                occupied[x] = false;
+            }
+        }
+
+        Ok(product)
+    }
+}
+
+/// Checks that the offsets of a compressed sparse Matrix start at zero, never
+/// decrease and end at `len`, the amount of stored values.
+pub(crate) fn check_offsets(offsets: &[usize], len: usize) -> Result<()> {
+    let mut previous = 0;
+    for (index, offset) in offsets.iter().copied().enumerate() {
+        if offset < previous || (index == 0 && offset != 0) {
+            return Err(Error::InvalidOffset { index, offset });
+        }
+        previous = offset;
+    }
+
+    if previous != len {
+        return Err(Error::IncorrectLength {
+            expected: len,
+            found: previous,
+        });
+    }
+
+    Ok(())
+}
+
+/// Appends a row or column of a compressed sparse Matrix from entries sorted by
+/// index, summing entries with the same index and dropping zeros.
+pub(crate) fn push_lane<T: Semiring>(
+    indices: &mut Vec<usize>,
+    values: &mut Vec<T>,
+    lane: impl IntoIterator<Item = (usize, T)>,
+) {
+    let start = values.len();
+
+    for (index, cell) in lane {
+        if values.len() > start && indices.last() == Some(&index) {
+            *values.last_mut().unwrap() += cell;
+        } else {
+            indices.push(index);
+            values.push(cell);
+        }
+    }
+
+    let mut kept = start;
+    for i in start..values.len() {
+        if !values[i].is_zero() {
+            indices[kept] = indices[i];
+            values[kept] = values[i];
+            kept += 1;
+        }
+    }
+    indices.truncate(kept);
+    values.truncate(kept);
+}
+
+impl<'a, T: 'a + Numeric> MatrixRef<'a, T> for SparseMatrix<T> {
+    fn get_x_len(&self) -> usize { self.x_len }
+    fn get_y_len(&self) -> usize { self.y_len }
+
+    fn get_at_unchecked(&self, x: usize, y: usize) -> T {
+        assert!(
+            x < self.x_len && y < self.y_len,
+            "Index ({}, {}) is out of range",
+            x,
+            y
+        );
+
+        let (indices, values) = self.row_entries(y);
+        match indices.binary_search(&x) {
+            Ok(index) => values[index],
+            Err(_) => T::zero(),
+        }
+    }
+
+    fn to_vec(&self) -> Vec<T> {
+        let mut data = vec![T::zero(); self.x_len * self.y_len];
+        for y in 0..self.y_len {
+            let (indices, values) = self.row_entries(y);
+            for (x, value) in indices.iter().zip(values) {
+                data[y * self.x_len + x] = *value;
+            }
+        }
+
+        data
+    }
+}
+
+impl<T: Numeric> PartialEq<HeapMatrix<T>> for SparseMatrix<T> {
+    fn eq(&self, other: &HeapMatrix<T>) -> bool {
+        self.x_len == other.x_len
+            && self.y_len == other.y_len
+            && self.to_vec() == *other.row_major_data()
+    }
+}
+
+impl<T: Numeric> PartialEq<SparseMatrix<T>> for HeapMatrix<T> {
+    fn eq(&self, other: &SparseMatrix<T>) -> bool { other == self }
+}
+
+impl<T: Numeric, const X: usize, const Y: usize> PartialEq<StackMatrix<T, X, Y>>
+    for SparseMatrix<T>
+where
+    [T; X * Y]: Sized,
+{
+    fn eq(&self, other: &StackMatrix<T, X, Y>) -> bool {
+        self.x_len == X && self.y_len == Y && self.to_vec() == other.data
+    }
+}
+
+impl<T: Numeric, const X: usize, const Y: usize> PartialEq<SparseMatrix<T>>
+    for StackMatrix<T, X, Y>
+where
+    [T; X * Y]: Sized,
+{
+    fn eq(&self, other: &SparseMatrix<T>) -> bool { other == self }
+}
+
+impl<T: Numeric + Display> Display for SparseMatrix<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_printable())
+    }
+}
+
+impl<T: Semiring> Mul for SparseMatrix<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output { &self * &rhs }
+}
+
+impl<T: Semiring> Mul for &SparseMatrix<T> {
+    type Output = SparseMatrix<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_sparse(rhs)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+impl<T: Semiring> Mul<HeapMatrix<T>> for SparseMatrix<T> {
+    type Output = HeapMatrix<T>;
+
+    fn mul(self, rhs: HeapMatrix<T>) -> Self::Output { &self * &rhs }
+}
+
+impl<T: Semiring> Mul<&HeapMatrix<T>> for &SparseMatrix<T> {
+    type Output = HeapMatrix<T>;
+
+    fn mul(self, rhs: &HeapMatrix<T>) -> Self::Output {
+        self.mul_dense(rhs)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+}
@@ -1,4 +1,10 @@
-use crate::{common::Numeric, implementations::HeapMatrix, matrix::*};
+use crate::{
+    common::{Numeric, Real, Ring, Semiring},
+    error::Result,
+    implementations::HeapMatrix,
+    matrix::*,
+    numbers::Complex,
+};
 use std::{
     fmt::{Debug, Display, Formatter},
     ops::*,
@@ -16,10 +22,10 @@ where
     pub(crate) y_len: usize,
 }
 
-impl<'a, T: 'a + Numeric, Mat, const X: usize, const Y: usize> Add<Mat>
+impl<'a, T: 'a + Semiring, Mat, const X: usize, const Y: usize> Add<Mat>
     for StackMatrix<T, X, Y>
 where
-    Mat: Matrix<'a, T> + Sized,
+    Mat: MatrixRef<'a, T> + Sized,
     [T; X * Y]: Sized,
 {
     type Output = Self;
@@ -65,7 +71,7 @@ where
 //     fn add(self, rhs: &HeapMatrix<T>) -> Self::Output {
 // self.mat_add(&rhs.clone()) } }
 
-impl<T: Numeric, const X: usize, const Y: usize> Sub for StackMatrix<T, X, Y>
+impl<T: Ring, const X: usize, const Y: usize> Sub for StackMatrix<T, X, Y>
 where
     [T; X * Y]: Sized,
 {
@@ -74,8 +80,7 @@ where
     fn sub(self, rhs: Self) -> Self::Output { self.mat_sub(&rhs) }
 }
 
-impl<T: Numeric, const X: usize, const Y: usize> Sub<HeapMatrix<T>>
-    for StackMatrix<T, X, Y>
+impl<T: Ring, const X: usize, const Y: usize> Sub<HeapMatrix<T>> for StackMatrix<T, X, Y>
 where
     [T; X * Y]: Sized,
 {
@@ -84,7 +89,7 @@ where
     fn sub(self, rhs: HeapMatrix<T>) -> Self::Output { self.mat_sub(&rhs) }
 }
 
-impl<T: Numeric, const X: usize, const Y: usize> Sub<&Self> for StackMatrix<T, X, Y>
+impl<T: Ring, const X: usize, const Y: usize> Sub<&Self> for StackMatrix<T, X, Y>
 where
     [T; X * Y]: Sized,
 {
@@ -93,8 +98,7 @@ where
     fn sub(self, rhs: &Self) -> Self::Output { self.mat_sub(rhs) }
 }
 
-impl<T: Numeric, const X: usize, const Y: usize> Sub<&HeapMatrix<T>>
-    for StackMatrix<T, X, Y>
+impl<T: Ring, const X: usize, const Y: usize> Sub<&HeapMatrix<T>> for StackMatrix<T, X, Y>
 where
     [T; X * Y]: Sized,
 {
@@ -103,19 +107,19 @@ where
     fn sub(self, rhs: &HeapMatrix<T>) -> Self::Output { self.mat_sub(rhs) }
 }
 
-impl<T: Numeric, const X: usize, const Y: usize, const Z: usize, const W: usize>
-    Mul<StackMatrix<T, Z, W>> for StackMatrix<T, X, Y>
+impl<T: Semiring, const X: usize, const Y: usize, const Z: usize>
+    Mul<StackMatrix<T, Z, X>> for StackMatrix<T, X, Y>
 where
     [T; X * Y]: Sized,
-    [T; Z * W]: Sized,
+    [T; Z * X]: Sized,
     [T; Z * Y]: Sized,
 {
     type Output = StackMatrix<T, Z, Y>;
 
-    fn mul(self, rhs: StackMatrix<T, Z, W>) -> Self::Output { self.mat_mul(&rhs) }
+    fn mul(self, rhs: StackMatrix<T, Z, X>) -> Self::Output { self.mat_mul(&rhs) }
 }
 
-impl<T: Numeric, const X: usize, const Y: usize> Mul<HeapMatrix<T>>
+impl<T: Semiring, const X: usize, const Y: usize> Mul<HeapMatrix<T>>
     for StackMatrix<T, X, Y>
 where
     [T; X * Y]: Sized,
@@ -125,19 +129,19 @@ where
     fn mul(self, rhs: HeapMatrix<T>) -> Self::Output { self.mat_mul(&rhs) }
 }
 
-impl<T: Numeric, const X: usize, const Y: usize, const Z: usize, const W: usize>
-    Mul<&StackMatrix<T, Z, W>> for StackMatrix<T, X, Y>
+impl<T: Semiring, const X: usize, const Y: usize, const Z: usize>
+    Mul<&StackMatrix<T, Z, X>> for StackMatrix<T, X, Y>
 where
     [T; X * Y]: Sized,
-    [T; Z * W]: Sized,
+    [T; Z * X]: Sized,
     [T; Z * Y]: Sized,
 {
     type Output = StackMatrix<T, Z, Y>;
 
-    fn mul(self, rhs: &StackMatrix<T, Z, W>) -> Self::Output { self.mat_mul(rhs) }
+    fn mul(self, rhs: &StackMatrix<T, Z, X>) -> Self::Output { self.mat_mul(rhs) }
 }
 
-impl<T: Numeric, const X: usize, const Y: usize> Mul<&HeapMatrix<T>>
+impl<T: Semiring, const X: usize, const Y: usize> Mul<&HeapMatrix<T>>
     for StackMatrix<T, X, Y>
 where
     [T; X * Y]: Sized,
@@ -160,7 +164,7 @@ where
 //     }
 // }
 
-impl<T: Numeric, const X: usize, const Y: usize> Sub<T> for StackMatrix<T, X, Y>
+impl<T: Ring, const X: usize, const Y: usize> Sub<T> for StackMatrix<T, X, Y>
 where
     [T; X * Y]: Sized,
 {
@@ -173,7 +177,7 @@ where
     }
 }
 
-impl<T: Numeric, const X: usize, const Y: usize> Mul<T> for StackMatrix<T, X, Y>
+impl<T: Semiring, const X: usize, const Y: usize> Mul<T> for StackMatrix<T, X, Y>
 where
     [T; X * Y]: Sized,
 {
@@ -222,7 +226,7 @@ where
     pub fn new_from_slice(data: &[T]) -> Self {
         assert!(data.len() == X * Y);
 
-        let mut array: [T; X * Y] = [T::default(); X * Y];
+        let mut array: [T; X * Y] = [T::zero(); X * Y];
 
         array
             .iter_mut()
@@ -236,6 +240,74 @@ where
             y_len: Y,
         }
     }
+
+    /// Returns the transpose of the StackMatrix, which has the x and y lengths
+    /// swapped in its type.
+    pub fn transpose(&self) -> StackMatrix<T, Y, X>
+    where
+        [T; Y * X]: Sized,
+    {
+        let mut data: [T; Y * X] = [T::zero(); Y * X];
+        transpose_into(&self.data, &mut data, X, Y);
+
+        StackMatrix::new_1d(data)
+    }
+}
+
+impl<T: Numeric, const X: usize, const Y: usize, const Z: usize>
+    HStack<StackMatrix<T, Z, Y>> for StackMatrix<T, X, Y>
+where
+    [T; X * Y]: Sized,
+    [T; Z * Y]: Sized,
+    [T; (X + Z) * Y]: Sized,
+{
+    type Output = StackMatrix<T, { X + Z }, Y>;
+
+    fn hstack(&self, rhs: &StackMatrix<T, Z, Y>) -> Result<Self::Output> {
+        Ok(StackMatrix::new_from_slice(&hstack_data(self, rhs)?))
+    }
+}
+
+impl<T: Numeric, const X: usize, const Y: usize> HStack<HeapMatrix<T>>
+    for StackMatrix<T, X, Y>
+where
+    [T; X * Y]: Sized,
+{
+    type Output = HeapMatrix<T>;
+
+    fn hstack(&self, rhs: &HeapMatrix<T>) -> Result<Self::Output> {
+        let data = hstack_data(self, rhs)?;
+
+        Ok(HeapMatrix::new(&data, X + rhs.x_len, Y))
+    }
+}
+
+impl<T: Numeric, const X: usize, const Y: usize, const Z: usize>
+    VStack<StackMatrix<T, X, Z>> for StackMatrix<T, X, Y>
+where
+    [T; X * Y]: Sized,
+    [T; X * Z]: Sized,
+    [T; X * (Y + Z)]: Sized,
+{
+    type Output = StackMatrix<T, X, { Y + Z }>;
+
+    fn vstack(&self, rhs: &StackMatrix<T, X, Z>) -> Result<Self::Output> {
+        Ok(StackMatrix::new_from_slice(&vstack_data(self, rhs)?))
+    }
+}
+
+impl<T: Numeric, const X: usize, const Y: usize> VStack<HeapMatrix<T>>
+    for StackMatrix<T, X, Y>
+where
+    [T; X * Y]: Sized,
+{
+    type Output = HeapMatrix<T>;
+
+    fn vstack(&self, rhs: &HeapMatrix<T>) -> Result<Self::Output> {
+        let data = vstack_data(self, rhs)?;
+
+        Ok(HeapMatrix::new(&data, X, Y + rhs.y_len))
+    }
 }
 
 impl<T: Numeric, const X: usize, const Y: usize> PartialEq<HeapMatrix<T>>
@@ -248,11 +320,12 @@ where
             return false;
         }
 
-        other.data == self.data
+        *other.row_major_data() == self.data[..]
     }
 }
 
-impl<T: Numeric, const X: usize, const Y: usize> Display for StackMatrix<T, X, Y>
+impl<T: Numeric + Display, const X: usize, const Y: usize> Display
+    for StackMatrix<T, X, Y>
 where
     [T; X * Y]: Sized,
 {
@@ -269,7 +342,7 @@ where
     fn mat_new(data: &[&[T]]) -> Self {
         assert!(data.len() == Y || data[0].len() == X);
 
-        let mut array: [[T; X]; Y] = [[T::default(); X]; Y];
+        let mut array: [[T; X]; Y] = [[T::zero(); X]; Y];
 
         array
             .iter_mut()
@@ -287,14 +360,24 @@ where
         Self::new(array)
     }
 
-    fn mat_new_1d(data: &[T], _columns: usize, _rows: usize) -> Self {
+    fn mat_new_1d(data: &[T], columns: usize, rows: usize) -> Self {
+        assert_eq!(
+            (columns, rows),
+            (X, Y),
+            "A {}x{} StackMatrix cannot hold a {}x{} Matrix",
+            X,
+            Y,
+            columns,
+            rows
+        );
+
         Self::new_from_slice(data)
     }
 
     fn mat_new_vec(data: Vec<Vec<T>>) -> Self {
         assert!(data.len() == Y || data[0].len() == X);
 
-        let mut array: [[T; X]; Y] = [[T::default(); X]; Y];
+        let mut array: [[T; X]; Y] = [[T::zero(); X]; Y];
 
         array
             .iter_mut()
@@ -311,6 +394,8 @@ where
 
         Self::new(array)
     }
+
+    fn fixed_dimensions() -> Option<(usize, usize)> { Some((X, Y)) }
 }
 
 impl<'a, T: 'a + Numeric, const X: usize, const Y: usize> MatrixRef<'a, T>
@@ -318,9 +403,18 @@ impl<'a, T: 'a + Numeric, const X: usize, const Y: usize> MatrixRef<'a, T>
 where
     [T; X * Y]: Sized,
 {
-    fn get_data(&self) -> &[T] { &self.data }
     fn get_x_len(&self) -> usize { self.x_len }
     fn get_y_len(&self) -> usize { self.y_len }
+    fn get_at_unchecked(&self, x: usize, y: usize) -> T { get_strided(self, x, y) }
+    fn to_vec(&self) -> Vec<T> { self.data.to_vec() }
+}
+
+impl<'a, T: 'a + Numeric, const X: usize, const Y: usize> DenseMatrixRef<'a, T>
+    for StackMatrix<T, X, Y>
+where
+    [T; X * Y]: Sized,
+{
+    fn get_data(&self) -> &[T] { &self.data }
 }
 
 impl<'a, T: 'a + Numeric, const X: usize, const Y: usize> MatrixRef<'a, T>
@@ -328,9 +422,18 @@ impl<'a, T: 'a + Numeric, const X: usize, const Y: usize> MatrixRef<'a, T>
 where
     [T; X * Y]: Sized,
 {
-    fn get_data(&self) -> &[T] { &self.data }
     fn get_x_len(&self) -> usize { self.x_len }
     fn get_y_len(&self) -> usize { self.y_len }
+    fn get_at_unchecked(&self, x: usize, y: usize) -> T { get_strided(self, x, y) }
+    fn to_vec(&self) -> Vec<T> { self.data.to_vec() }
+}
+
+impl<'a, T: 'a + Numeric, const X: usize, const Y: usize> DenseMatrixRef<'a, T>
+    for &StackMatrix<T, X, Y>
+where
+    [T; X * Y]: Sized,
+{
+    fn get_data(&self) -> &[T] { &self.data }
 }
 
 impl<'a, T: 'a + Numeric, const X: usize, const Y: usize> Matrix<'a, T>
@@ -341,9 +444,28 @@ where
     fn get_data_mut(&mut self) -> &mut [T] { &mut self.data }
 }
 
-impl<'a, T: 'a + Numeric, const X: usize, const Y: usize> MatrixOp<'a, T>
+impl<'a, T: 'a + Semiring, const X: usize, const Y: usize> MatrixOp<'a, T>
+    for StackMatrix<T, X, Y>
+where
+    [T; X * Y]: Sized,
+{
+}
+
+impl<'a, T: 'a + Semiring, const N: usize> SquareMatrix<'a, T> for StackMatrix<T, N, N> where
+    [T; N * N]: Sized
+{
+}
+
+impl<'a, T: 'a + Real, const X: usize, const Y: usize> RealMatrix<'a, T>
     for StackMatrix<T, X, Y>
 where
     [T; X * Y]: Sized,
 {
 }
+
+impl<'a, T: 'a + Ring, const X: usize, const Y: usize> ComplexMatrix<'a, T>
+    for StackMatrix<Complex<T>, X, Y>
+where
+    [Complex<T>; X * Y]: Sized,
+{
+}
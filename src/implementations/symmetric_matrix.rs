@@ -0,0 +1,182 @@
+use crate::{
+    common::{Field, Numeric, Ring, Semiring},
+    decompositions::LuDecomposition,
+    error::{Error, Result},
+    implementations::{lower_index, HeapMatrix},
+    matrix::*,
+};
+use std::{
+    fmt::{Display, Formatter},
+    ops::*,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+/// A square Matrix which is equal to its transpose. Only the lower triangle is
+/// stored, packed row by row, so row `y` holds the elements in the columns
+/// `0..=y` and takes up half the memory of a full `n` by `n` Matrix.
+pub struct SymmetricMatrix<T: Numeric> {
+    pub(crate) data: Vec<T>,
+    pub(crate) n: usize,
+}
+
+impl<T: Numeric> SymmetricMatrix<T> {
+    /// Creates a new `n` by `n` SymmetricMatrix from its packed lower
+    /// triangle, returning `Error::IncorrectLength` if `data` does not have
+    /// `n * (n + 1) / 2` elements.
+    pub fn new(n: usize, data: &[T]) -> Result<Self> {
+        if data.len() != n * (n + 1) / 2 {
+            return Err(Error::IncorrectLength {
+                expected: n * (n + 1) / 2,
+                found: data.len(),
+            });
+        }
+
+        Ok(Self {
+            data: data.to_vec(),
+            n,
+        })
+    }
+
+    /// Creates a new SymmetricMatrix from the lower triangle of any other
+    /// Matrix, returning `Error::NotSquare` if it is not square. The upper
+    /// triangle is not read, so it is assumed to mirror the lower triangle.
+    pub fn from_dense<'a>(mat: &impl MatrixRef<'a, T>) -> Result<Self> {
+        check_square(mat)?;
+
+        let n = mat.get_x_len();
+        let mut data = Vec::with_capacity(n * (n + 1) / 2);
+        for y in 0..n {
+            for x in 0..=y {
+                data.push(mat.get_at_unchecked(x, y));
+            }
+        }
+
+        Ok(Self { data, n })
+    }
+
+    /// Copies the SymmetricMatrix into a new HeapMatrix.
+    pub fn to_heap_matrix(&self) -> HeapMatrix<T> {
+        HeapMatrix::new(&self.to_vec(), self.n, self.n)
+    }
+
+    /// Gets the packed lower triangle, row by row.
+    pub fn data(&self) -> &[T] { &self.data }
+
+    fn index(&self, x: usize, y: usize) -> usize {
+        if x <= y {
+            lower_index(self.n, x, y)
+        } else {
+            lower_index(self.n, y, x)
+        }
+    }
+}
+
+impl<T: Semiring> SymmetricMatrix<T> {
+    /// Multiplies the SymmetricMatrix by the vector `x` in O(n^2) time,
+    /// returning `Error::IncorrectLength` if `x` does not have `n` elements.
+    pub fn mul_vec(&self, x: &[T]) -> Result<Vec<T>> {
+        check_vec_len(self.n, x)?;
+
+        let mut product = vec![T::zero(); self.n];
+        for y in 0..self.n {
+            for column in 0..y {
+                let cell = self.data[lower_index(self.n, column, y)];
+                product[y] += cell * x[column];
+                product[column] += cell * x[y];
+            }
+            product[y] += self.data[lower_index(self.n, y, y)] * x[y];
+        }
+
+        Ok(product)
+    }
+
+    /// Computes the matrix product of the SymmetricMatrix and any other
+    /// Matrix, returning `Error::DimensionMismatch` if `rhs` does not have `n`
+    /// rows.
+    pub fn mul_dense<'a>(&self, rhs: &impl MatrixRef<'a, T>) -> Result<HeapMatrix<T>> {
+        map_columns(self.n, rhs, |column| self.mul_vec(column))
+    }
+}
+
+impl<T: Field + PartialOrd> SymmetricMatrix<T> {
+    /// Solves `A * x = b` for `x`, returning `Error::IncorrectLength` if `b`
+    /// does not have `n` elements or `Error::Singular` if `A` is singular. A
+    /// SymmetricMatrix is not necessarily definite, so this uses an LU
+    /// decomposition with partial pivoting and takes O(n^3) time; use
+    /// `CholeskyDecomposition` instead for positive-definite Matrices that are
+    /// solved against many times.
+    pub fn solve_vec(&self, b: &[T]) -> Result<Vec<T>> {
+        LuDecomposition::new(self)?.solve_vec(b)
+    }
+
+    /// Solves `A * X = B` for `X`, treating each column of `B` as a separate
+    /// right-hand side. Returns `Error::DimensionMismatch` if `B` does not
+    /// have `n` rows or `Error::Singular` if `A` is singular.
+    pub fn solve<'a>(&self, b: &impl MatrixRef<'a, T>) -> Result<HeapMatrix<T>> {
+        LuDecomposition::new(self)?.solve(b)
+    }
+}
+
+impl<T: Semiring> Add for SymmetricMatrix<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        check_same_dimensions(&self, &rhs).unwrap_or_else(|error| panic!("{}", error));
+
+        Self {
+            data: self
+                .data
+                .into_iter()
+                .zip(rhs.data)
+                .map(|(l, r)| l + r)
+                .collect(),
+            n: self.n,
+        }
+    }
+}
+
+impl<T: Ring> Sub for SymmetricMatrix<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        check_same_dimensions(&self, &rhs).unwrap_or_else(|error| panic!("{}", error));
+
+        Self {
+            data: self
+                .data
+                .into_iter()
+                .zip(rhs.data)
+                .map(|(l, r)| l - r)
+                .collect(),
+            n: self.n,
+        }
+    }
+}
+
+impl<T: Semiring> Mul for SymmetricMatrix<T> {
+    type Output = HeapMatrix<T>;
+
+    // The product of two symmetric Matrices is not symmetric in general.
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_dense(&rhs)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+impl_structured_ops!(SymmetricMatrix);
+
+impl<'a, T: 'a + Numeric> MatrixRef<'a, T> for SymmetricMatrix<T> {
+    fn get_x_len(&self) -> usize { self.n }
+    fn get_y_len(&self) -> usize { self.n }
+
+    fn get_at_unchecked(&self, x: usize, y: usize) -> T {
+        assert!(
+            x < self.n && y < self.n,
+            "Index ({}, {}) is out of range",
+            x,
+            y
+        );
+
+        self.data[self.index(x, y)]
+    }
+}
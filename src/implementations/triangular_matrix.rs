@@ -0,0 +1,308 @@
+use crate::{
+    common::{Field, Numeric, Ring, Semiring},
+    error::{Error, Result},
+    implementations::HeapMatrix,
+    matrix::*,
+};
+use std::{
+    fmt::{Display, Formatter},
+    ops::*,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+/// A square Matrix whose elements below the diagonal are all zero. Only the
+/// upper triangle is stored, packed row by row, so row `y` holds the elements
+/// in the columns `y..n`.
+pub struct UpperTriangular<T: Numeric> {
+    pub(crate) data: Vec<T>,
+    pub(crate) n: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A square Matrix whose elements above the diagonal are all zero. Only the
+/// lower triangle is stored, packed row by row, so row `y` holds the elements
+/// in the columns `0..=y`.
+pub struct LowerTriangular<T: Numeric> {
+    pub(crate) data: Vec<T>,
+    pub(crate) n: usize,
+}
+
+/// Gets the position of the element at `(x, y)`, where `x >= y`, in the packed
+/// upper triangle of an `n` by `n` Matrix.
+fn upper_index(n: usize, x: usize, y: usize) -> usize { y * (2 * n + 1 - y) / 2 + x - y }
+
+/// Gets the position of the element at `(x, y)`, where `x <= y`, in the packed
+/// lower triangle of a Matrix.
+pub(crate) fn lower_index(_n: usize, x: usize, y: usize) -> usize { y * (y + 1) / 2 + x }
+
+fn in_upper(x: usize, y: usize) -> bool { x >= y }
+
+fn in_lower(x: usize, y: usize) -> bool { x <= y }
+
+/// Implements the parts of the triangular Matrix types which only differ in
+/// which triangle is stored.
+macro_rules! impl_triangular {
+    ($t:ident, $index:ident, $contains:ident) => {
+        impl<T: Numeric> $t<T> {
+            /// Creates a new `n` by `n` Matrix from its packed triangle,
+            /// returning `Error::IncorrectLength` if `data` does not have
+            /// `n * (n + 1) / 2` elements.
+            pub fn new(n: usize, data: &[T]) -> Result<Self> {
+                if data.len() != n * (n + 1) / 2 {
+                    return Err(Error::IncorrectLength {
+                        expected: n * (n + 1) / 2,
+                        found: data.len(),
+                    });
+                }
+
+                Ok(Self {
+                    data: data.to_vec(),
+                    n,
+                })
+            }
+
+            /// Creates a new Matrix from the same triangle of any other Matrix,
+            /// returning `Error::NotSquare` if it is not square. The other
+            /// triangle is not read, so it is assumed to be zero.
+            pub fn from_dense<'a>(mat: &impl MatrixRef<'a, T>) -> Result<Self> {
+                check_square(mat)?;
+
+                let n = mat.get_x_len();
+                let mut data = Vec::with_capacity(n * (n + 1) / 2);
+                for y in 0..n {
+                    for x in (0..n).filter(|x| $contains(*x, y)) {
+                        data.push(mat.get_at_unchecked(x, y));
+                    }
+                }
+
+                Ok(Self { data, n })
+            }
+
+            /// Copies the Matrix into a new HeapMatrix.
+            pub fn to_heap_matrix(&self) -> HeapMatrix<T> {
+                HeapMatrix::new(&self.to_vec(), self.n, self.n)
+            }
+
+            /// Gets the packed triangle, row by row.
+            pub fn data(&self) -> &[T] { &self.data }
+        }
+
+        impl<T: Semiring> $t<T> {
+            /// Returns the determinant, which is the product of the diagonal.
+            pub fn determinant(&self) -> T {
+                (0..self.n).fold(T::one(), |product, i| {
+                    product * self.data[$index(self.n, i, i)]
+                })
+            }
+
+            /// Multiplies the Matrix by the vector `x` in O(n^2) time,
+            /// returning `Error::IncorrectLength` if `x` does not have as many
+            /// elements as there are columns.
+            pub fn mul_vec(&self, x: &[T]) -> Result<Vec<T>> {
+                check_vec_len(self.n, x)?;
+
+                Ok((0..self.n)
+                    .map(|y| {
+                        (0..self.n).filter(|column| $contains(*column, y)).fold(
+                            T::zero(),
+                            |sum, column| {
+                                sum + self.data[$index(self.n, column, y)] * x[column]
+                            },
+                        )
+                    })
+                    .collect())
+            }
+
+            /// Computes the matrix product of the Matrix and any other Matrix,
+            /// returning `Error::DimensionMismatch` if `rhs` does not have as
+            /// many rows as there are columns.
+            pub fn mul_dense<'a>(
+                &self,
+                rhs: &impl MatrixRef<'a, T>,
+            ) -> Result<HeapMatrix<T>> {
+                map_columns(self.n, rhs, |column| self.mul_vec(column))
+            }
+        }
+
+        impl<T: Field> $t<T> {
+            /// Solves `A * X = B` for `X`, treating each column of `B` as a
+            /// separate right-hand side. Returns `Error::DimensionMismatch` if
+            /// `B` does not have the same amount of rows as `A`, or
+            /// `Error::Singular` if the diagonal contains a zero.
+            pub fn solve<'a>(&self, b: &impl MatrixRef<'a, T>) -> Result<HeapMatrix<T>> {
+                map_columns(self.n, b, |column| self.solve_vec(column))
+            }
+        }
+
+        impl<T: Semiring> Add for $t<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                check_same_dimensions(&self, &rhs)
+                    .unwrap_or_else(|error| panic!("{}", error));
+
+                Self {
+                    data: self
+                        .data
+                        .into_iter()
+                        .zip(rhs.data)
+                        .map(|(l, r)| l + r)
+                        .collect(),
+                    n: self.n,
+                }
+            }
+        }
+
+        impl<T: Ring> Sub for $t<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                check_same_dimensions(&self, &rhs)
+                    .unwrap_or_else(|error| panic!("{}", error));
+
+                Self {
+                    data: self
+                        .data
+                        .into_iter()
+                        .zip(rhs.data)
+                        .map(|(l, r)| l - r)
+                        .collect(),
+                    n: self.n,
+                }
+            }
+        }
+
+        impl<T: Semiring> Mul for $t<T> {
+            type Output = Self;
+
+            // The product of two Matrices with the same triangle stored has
+            // the same triangle stored.
+            fn mul(self, rhs: Self) -> Self::Output {
+                check_product_dimensions(&self, &rhs)
+                    .unwrap_or_else(|error| panic!("{}", error));
+
+                let n = self.n;
+                let mut data = Vec::with_capacity(self.data.len());
+                for y in 0..n {
+                    for x in (0..n).filter(|x| $contains(*x, y)) {
+                        // Only the elements between the row and the column can
+                        // be non-zero in both Matrices.
+                        let cell = (y.min(x)..=y.max(x)).fold(T::zero(), |sum, k| {
+                            sum + self.data[$index(n, k, y)] * rhs.data[$index(n, x, k)]
+                        });
+                        data.push(cell);
+                    }
+                }
+
+                Self { data, n }
+            }
+        }
+
+        impl_structured_ops!($t);
+
+        impl<'a, T: 'a + Numeric> MatrixRef<'a, T> for $t<T> {
+            fn get_x_len(&self) -> usize { self.n }
+            fn get_y_len(&self) -> usize { self.n }
+
+            fn get_at_unchecked(&self, x: usize, y: usize) -> T {
+                assert!(
+                    x < self.n && y < self.n,
+                    "Index ({}, {}) is out of range",
+                    x,
+                    y
+                );
+
+                if $contains(x, y) {
+                    self.data[$index(self.n, x, y)]
+                } else {
+                    T::zero()
+                }
+            }
+        }
+    };
+}
+
+impl_triangular!(UpperTriangular, upper_index, in_upper);
+impl_triangular!(LowerTriangular, lower_index, in_lower);
+
+impl<T: Numeric> UpperTriangular<T> {
+    /// Returns the transpose, which is lower triangular.
+    pub fn transpose(&self) -> LowerTriangular<T> {
+        let n = self.n;
+        let mut data = Vec::with_capacity(self.data.len());
+        for y in 0..n {
+            for x in 0..=y {
+                data.push(self.data[upper_index(n, y, x)]);
+            }
+        }
+
+        LowerTriangular { data, n }
+    }
+}
+
+impl<T: Numeric> LowerTriangular<T> {
+    /// Returns the transpose, which is upper triangular.
+    pub fn transpose(&self) -> UpperTriangular<T> {
+        let n = self.n;
+        let mut data = Vec::with_capacity(self.data.len());
+        for y in 0..n {
+            for x in y..n {
+                data.push(self.data[lower_index(n, y, x)]);
+            }
+        }
+
+        UpperTriangular { data, n }
+    }
+}
+
+impl<T: Field> UpperTriangular<T> {
+    /// Solves `U * x = b` for `x` by back substitution in O(n^2) time,
+    /// returning `Error::IncorrectLength` if `b` does not have `n` elements or
+    /// `Error::Singular` if the diagonal contains a zero.
+    pub fn solve_vec(&self, b: &[T]) -> Result<Vec<T>> {
+        check_vec_len(self.n, b)?;
+
+        let n = self.n;
+        let mut x = b.to_vec();
+        for y in (0..n).rev() {
+            for column in y + 1..n {
+                let cell = self.data[upper_index(n, column, y)] * x[column];
+                x[y] -= cell;
+            }
+
+            let diagonal = self.data[upper_index(n, y, y)];
+            if diagonal.is_zero() {
+                return Err(Error::Singular);
+            }
+            x[y] /= diagonal;
+        }
+
+        Ok(x)
+    }
+}
+
+impl<T: Field> LowerTriangular<T> {
+    /// Solves `L * x = b` for `x` by forward substitution in O(n^2) time,
+    /// returning `Error::IncorrectLength` if `b` does not have `n` elements or
+    /// `Error::Singular` if the diagonal contains a zero.
+    pub fn solve_vec(&self, b: &[T]) -> Result<Vec<T>> {
+        check_vec_len(self.n, b)?;
+
+        let n = self.n;
+        let mut x = b.to_vec();
+        for y in 0..n {
+            for column in 0..y {
+                let cell = self.data[lower_index(n, column, y)] * x[column];
+                x[y] -= cell;
+            }
+
+            let diagonal = self.data[lower_index(n, y, y)];
+            if diagonal.is_zero() {
+                return Err(Error::Singular);
+            }
+            x[y] /= diagonal;
+        }
+
+        Ok(x)
+    }
+}
@@ -0,0 +1,341 @@
+use std::iter::{StepBy, Take};
+
+#[derive(Debug, Clone)]
+/// An iterator over a row or column of a Matrix, which steps through the inner
+/// data of the Matrix by the stride between the elements of the row or column.
+pub struct StridedIter<'a, T> {
+    iter: Take<StepBy<std::slice::Iter<'a, T>>>,
+}
+
+impl<'a, T> StridedIter<'a, T> {
+    /// Creates an iterator over `len` elements of `data`, starting at `start`
+    /// and `step` elements apart.
+    pub(crate) fn new(data: &'a [T], start: usize, step: usize, len: usize) -> Self {
+        let data = if len == 0 { &data[..0] } else { &data[start..] };
+
+        Self {
+            iter: data.iter().step_by(step.max(1)).take(len),
+        }
+    }
+}
+
+impl<'a, T> Iterator for StridedIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> { self.iter.next() }
+    fn size_hint(&self) -> (usize, Option<usize>) { self.iter.size_hint() }
+}
+
+impl<'a, T> DoubleEndedIterator for StridedIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> { self.iter.next_back() }
+}
+
+impl<'a, T> ExactSizeIterator for StridedIter<'a, T> {}
+
+#[derive(Debug)]
+/// An iterator over mutable references to the elements of a row or column of
+/// a Matrix.
+pub struct StridedIterMut<'a, T> {
+    elements: Elements<'a, T>,
+}
+
+#[derive(Debug)]
+enum Elements<'a, T> {
+    /// The elements are a fixed step apart in a borrowed slice.
+    Strided(Take<StepBy<std::slice::IterMut<'a, T>>>),
+    /// The elements were taken one by one from lanes which interleave with
+    /// other lanes, see [`LanesMut`].
+    Gathered(std::vec::IntoIter<&'a mut T>),
+}
+
+impl<'a, T> StridedIterMut<'a, T> {
+    /// Creates an iterator over `len` elements of `data`, starting at `start`
+    /// and `step` elements apart.
+    pub(crate) fn new(data: &'a mut [T], start: usize, step: usize, len: usize) -> Self {
+        let data = if len == 0 {
+            &mut data[..0]
+        } else {
+            &mut data[start..]
+        };
+
+        Self {
+            elements: Elements::Strided(data.iter_mut().step_by(step.max(1)).take(len)),
+        }
+    }
+}
+
+impl<'a, T> Iterator for StridedIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.elements {
+            Elements::Strided(iter) => iter.next(),
+            Elements::Gathered(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.elements {
+            Elements::Strided(iter) => iter.size_hint(),
+            Elements::Gathered(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for StridedIterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match &mut self.elements {
+            Elements::Strided(iter) => iter.next_back(),
+            Elements::Gathered(iter) => iter.next_back(),
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for StridedIterMut<'a, T> {}
+
+#[derive(Debug, Clone)]
+/// An iterator over the rows or the columns of a Matrix, which yields a
+/// [`StridedIter`] for each of them.
+pub struct Lanes<'a, T> {
+    data: &'a [T],
+    lane_step: usize,
+    lane_len: usize,
+    element_step: usize,
+    next: usize,
+    end: usize,
+}
+
+impl<'a, T> Lanes<'a, T> {
+    /// Creates an iterator over `count` lanes of `data` which start
+    /// `lane_step` elements apart, where each lane has `lane_len` elements that
+    /// are `element_step` elements apart.
+    pub(crate) fn new(
+        data: &'a [T],
+        count: usize,
+        lane_step: usize,
+        lane_len: usize,
+        element_step: usize,
+    ) -> Self {
+        Self {
+            data,
+            lane_step,
+            lane_len,
+            element_step,
+            next: 0,
+            end: count,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Lanes<'a, T> {
+    type Item = StridedIter<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == self.end {
+            return None;
+        }
+
+        let lane = StridedIter::new(
+            self.data,
+            self.next * self.lane_step,
+            self.element_step,
+            self.lane_len,
+        );
+        self.next += 1;
+
+        Some(lane)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.next;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Lanes<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.next == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+
+        Some(StridedIter::new(
+            self.data,
+            self.end * self.lane_step,
+            self.element_step,
+            self.lane_len,
+        ))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Lanes<'a, T> {}
+
+/// Returns whether `count` lanes which start `lane_step` elements apart, and
+/// each have `lane_len` elements that are `element_step` elements apart, lie
+/// in separate ranges of data without any element being shared.
+pub(crate) fn lanes_separate(
+    count: usize,
+    lane_len: usize,
+    lane_step: usize,
+    element_step: usize,
+) -> bool {
+    (lane_len <= 1 || element_step > 0)
+        && (count <= 1 || lane_len == 0 || lane_step > (lane_len - 1) * element_step)
+}
+
+#[derive(Debug)]
+/// An iterator over the rows or the columns of a Matrix, which yields a
+/// [`StridedIterMut`] for each of them.
+pub struct LanesMut<'a, T> {
+    layout: LaneLayout<'a, T>,
+    lane_step: usize,
+    lane_len: usize,
+    element_step: usize,
+    remaining: usize,
+}
+
+#[derive(Debug)]
+enum LaneLayout<'a, T> {
+    /// Each lane lies in its own range of the data, such as the rows of a
+    /// row-major Matrix, so the remaining lanes are split off one at a time.
+    Separate(&'a mut [T]),
+    /// The lanes interleave, such as the columns of a row-major Matrix, so the
+    /// data is split into bands which each hold one element of every lane, and
+    /// each lane takes its element from the front or back of every band.
+    Interleaved(Vec<&'a mut [T]>),
+}
+
+impl<'a, T> LanesMut<'a, T> {
+    /// Creates an iterator over `count` lanes of `data` like [`Lanes::new`].
+    /// Panics unless either the lanes, or the bands holding one element of
+    /// every lane, lie in separate ranges of `data`, which rules out any two
+    /// lanes sharing an element.
+    pub(crate) fn new(
+        data: &'a mut [T],
+        count: usize,
+        lane_step: usize,
+        lane_len: usize,
+        element_step: usize,
+    ) -> Self {
+        let layout = if lanes_separate(count, lane_len, lane_step, element_step) {
+            LaneLayout::Separate(data)
+        } else {
+            assert!(
+                lanes_separate(lane_len, count, element_step, lane_step),
+                "The lanes of a mutable matrix must not overlap"
+            );
+
+            let mut bands = Vec::with_capacity(lane_len);
+            let mut rest = data;
+            for band in 0..lane_len {
+                let split = if band + 1 == lane_len {
+                    rest.len()
+                } else {
+                    element_step
+                };
+                let (band, remainder) = rest.split_at_mut(split);
+                bands.push(band);
+                rest = remainder;
+            }
+
+            LaneLayout::Interleaved(bands)
+        };
+
+        Self {
+            layout,
+            lane_step,
+            lane_len,
+            element_step,
+            remaining: count,
+        }
+    }
+}
+
+impl<'a, T> Iterator for LanesMut<'a, T> {
+    type Item = StridedIterMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        // The last lane takes whatever is left, as the data may end less than
+        // `lane_step` elements after the start of it.
+        let split = if self.remaining == 1 {
+            usize::MAX
+        } else {
+            self.lane_step
+        };
+        self.remaining -= 1;
+
+        Some(match &mut self.layout {
+            LaneLayout::Separate(rest) => {
+                let rest = std::mem::take(rest);
+                let (lane, remainder) = rest.split_at_mut(split.min(rest.len()));
+                self.layout = LaneLayout::Separate(remainder);
+
+                StridedIterMut::new(lane, 0, self.element_step, self.lane_len)
+            }
+            LaneLayout::Interleaved(bands) => StridedIterMut {
+                elements: Elements::Gathered(
+                    bands
+                        .iter_mut()
+                        .map(|band| {
+                            let band_data = std::mem::take(band);
+                            let (front, remainder) =
+                                band_data.split_at_mut(split.min(band_data.len()));
+                            *band = remainder;
+
+                            &mut front[0]
+                        })
+                        .collect::<Vec<_>>()
+                        .into_iter(),
+                ),
+            },
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for LanesMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        let offset = self.remaining * self.lane_step;
+
+        Some(match &mut self.layout {
+            LaneLayout::Separate(rest) => {
+                let rest = std::mem::take(rest);
+                let (remainder, lane) = rest.split_at_mut(offset.min(rest.len()));
+                self.layout = LaneLayout::Separate(remainder);
+
+                StridedIterMut::new(lane, 0, self.element_step, self.lane_len)
+            }
+            LaneLayout::Interleaved(bands) => StridedIterMut {
+                elements: Elements::Gathered(
+                    bands
+                        .iter_mut()
+                        .map(|band| {
+                            let (remainder, back) =
+                                std::mem::take(band).split_at_mut(offset);
+                            *band = remainder;
+
+                            &mut back[0]
+                        })
+                        .collect::<Vec<_>>()
+                        .into_iter(),
+                ),
+            },
+        })
+    }
+}
+
+impl<'a, T> ExactSizeIterator for LanesMut<'a, T> {}
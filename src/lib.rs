@@ -16,15 +16,37 @@
 extern crate test;
 
 mod common;
+mod decompositions;
 mod error;
 mod implementations;
+mod iterators;
 mod matrix;
+mod numbers;
 pub mod prelude;
+mod solvers;
 
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
 
+    fn assert_approx_eq<'a>(
+        lhs: &impl MatrixRef<'a, f64>,
+        rhs: &impl MatrixRef<'a, f64>,
+    ) {
+        assert_eq!(lhs.get_x_len(), rhs.get_x_len());
+        assert_eq!(lhs.get_y_len(), rhs.get_y_len());
+
+        for (l, r) in lhs.to_vec().iter().zip(rhs.to_vec()) {
+            assert!(
+                (l - r).abs() < 1e-9,
+                "{} != {}\n{}",
+                l,
+                r,
+                lhs.to_printable()
+            );
+        }
+    }
+
     #[test]
     fn stack_index_check() {
         let mat1 = StackMatrix::new([[100, 200], [300, 400]]);
@@ -62,6 +84,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn stack_mul_non_square_check() {
+        let mat1 = StackMatrix::new([[1, 2, 3], [4, 5, 6]]);
+        let mat2 = StackMatrix::new([[7], [8], [9]]);
+
+        let res: StackMatrix<i32, 1, 2> = mat1 * mat2;
+
+        assert_eq!(res, StackMatrix::new([[50], [122]]));
+    }
+
+    #[test]
+    fn stack_dot_check() {
+        let mat1 = StackMatrix::new([[1, 2], [3, 4], [5, 6]]);
+        let mat2 = StackMatrix::new([[1, 2, 3], [4, 5, 6]]);
+
+        let res: StackMatrix<i32, 3, 3> = mat1.dot_prod(&mat2);
+
+        assert_eq!(res, mat1 * mat2);
+    }
+
+    #[test]
+    fn stack_hadamard_check() {
+        let mat1 = StackMatrix::new([[1, 2], [3, 4]]);
+        let mat2 = StackMatrix::new([[5, 6], [7, 8]]);
+
+        assert_eq!(
+            mat1.hadamard_prod(&mat2),
+            StackMatrix::new([[5, 12], [21, 32]])
+        );
+    }
+
     #[test]
     fn heap_index_check() {
         let mat1 = HeapMatrix::new_owned_2d([[100, 200], [300, 400]]);
@@ -102,6 +155,86 @@ mod tests {
         );
     }
 
+    #[test]
+    fn heap_mul_non_square_check() {
+        let mat1 = HeapMatrix::new_owned_2d([[1, 2, 3], [4, 5, 6]]);
+        let mat2 = HeapMatrix::new_owned_2d([[7], [8], [9]]);
+
+        assert_eq!(mat1 * mat2, HeapMatrix::new_owned_2d([[50], [122]]));
+    }
+
+    #[test]
+    fn heap_hadamard_check() {
+        let mat1 = HeapMatrix::new_owned_2d([[1, 2, 3], [4, 5, 6]]);
+        let mat2 = HeapMatrix::new_owned_2d([[6, 5, 4], [3, 2, 1]]);
+
+        assert_eq!(
+            mat1.hadamard_prod(&mat2),
+            HeapMatrix::new_owned_2d([[6, 10, 12], [12, 10, 6]])
+        );
+    }
+
+    #[test]
+    fn heap_checked_add_check() {
+        let mat1 = HeapMatrix::new_owned_2d([[1, 2, 3], [4, 5, 6]]);
+        let mat2 = HeapMatrix::new_owned_2d([[1, 2], [3, 4], [5, 6]]);
+
+        assert_eq!(
+            mat1.checked_add(&mat1),
+            Ok(HeapMatrix::new_owned_2d([[2, 4, 6], [8, 10, 12]]))
+        );
+        assert_eq!(
+            mat1.checked_add(&mat2),
+            Err(Error::DimensionMismatch {
+                expected: (3, 2),
+                found: (2, 3)
+            })
+        );
+        assert!(mat1.checked_sub(&mat2).is_err());
+        assert!(mat1.checked_hadamard(&mat2).is_err());
+    }
+
+    #[test]
+    fn heap_checked_mul_check() {
+        let mat1 = HeapMatrix::new_owned_2d([[1, 2, 3], [4, 5, 6]]);
+        let mat2 = HeapMatrix::new_owned_2d([[7], [8], [9]]);
+
+        assert_eq!(
+            mat1.checked_dot(&mat2),
+            Ok(HeapMatrix::new_owned_2d([[50], [122]]))
+        );
+        assert_eq!(
+            mat2.checked_mul::<_, HeapMatrix<i32>>(&mat1),
+            Err(Error::DimensionMismatch {
+                expected: (3, 1),
+                found: (3, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn stack_heap_checked_check() {
+        let stack_mat = StackMatrix::new([[1, 2], [3, 4]]);
+        let heap_mat = HeapMatrix::new_owned_2d([[1, 2, 3], [4, 5, 6]]);
+
+        assert!(stack_mat.checked_add(&heap_mat).is_err());
+        assert_eq!(
+            stack_mat.checked_dot(&heap_mat),
+            Ok(HeapMatrix::new_owned_2d([[9, 12, 15], [19, 26, 33]]))
+        );
+        assert_eq!(
+            stack_mat.checked_mul::<_, StackMatrix<i32, 3, 2>>(&heap_mat),
+            Ok(StackMatrix::new([[9, 12, 15], [19, 26, 33]]))
+        );
+        assert_eq!(
+            stack_mat.checked_mul::<_, StackMatrix<i32, 2, 2>>(&heap_mat),
+            Err(Error::DimensionMismatch {
+                expected: (2, 2),
+                found: (3, 2)
+            })
+        );
+    }
+
     #[test]
     fn heap_insert_row_check() {
         let mut mat = HeapMatrix::new_owned_2d([[1, 2], [3, 4]]);
@@ -110,7 +243,40 @@ mod tests {
         assert_eq!(mat, HeapMatrix::new_owned_2d([[1, 2], [3, 4], [5, 6]]));
 
         let res = mat.insert_row([7, 8, 9]);
-        assert_eq!(res, Err(Error::IncorrectLength));
+        assert_eq!(
+            res,
+            Err(Error::IncorrectLength {
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn heap_get_at_out_of_range_check() {
+        let mat = HeapMatrix::new_owned_2d([[1, 2, 3], [4, 5, 6]]);
+
+        assert_eq!(mat.get_at(2, 1), Ok(6));
+        assert_eq!(
+            mat.get_at(3, 0),
+            Err(Error::OutOfRange {
+                index: (3, 0),
+                bounds: (3, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn error_display_check() {
+        let err: Box<dyn std::error::Error> = Box::new(Error::DimensionMismatch {
+            expected: (3, 2),
+            found: (2, 3),
+        });
+
+        assert_eq!(
+            err.to_string(),
+            "expected a matrix with dimensions (3, 2) but found (2, 3)"
+        );
     }
 
     #[test]
@@ -170,4 +336,1955 @@ mod tests {
 
         assert_eq!(stack_mat, heap_mat);
     }
+
+    #[test]
+    fn stack_determinant_check() {
+        let mat = StackMatrix::new([[2, -3, 1], [2, 0, -1], [1, 4, 5]]);
+
+        assert_eq!(mat.determinant(), Ok(49));
+        assert_eq!(mat.trace(), Ok(7));
+        assert_eq!(StackMatrix::new([[1, 2], [2, 4]]).determinant(), Ok(0));
+        assert_eq!(StackMatrix::new([[0, 1], [1, 0]]).determinant(), Ok(-1));
+    }
+
+    #[test]
+    fn stack_inverse_check() {
+        let mat = StackMatrix::new([[2, 1], [1, 1]]);
+
+        assert_eq!(mat.try_inverse(), Ok(StackMatrix::new([[1, -1], [-1, 2]])));
+        assert_eq!(
+            StackMatrix::new([[1, 2], [2, 4]]).try_inverse(),
+            Err(Error::Singular)
+        );
+        assert_eq!(
+            StackMatrix::new([[2, 0], [0, 2]]).try_inverse(),
+            Err(Error::NotRepresentable)
+        );
+    }
+
+    #[test]
+    fn unsigned_square_check() {
+        let mat: StackMatrix<u32, 3, 3> =
+            StackMatrix::new([[2, 3, 1], [1, 2, 1], [1, 1, 3]]);
+
+        assert_eq!(mat.determinant(), Ok(3));
+        assert_eq!(mat.rank(), 3);
+        assert_eq!(
+            StackMatrix::<u32, 2, 2>::new([[1, 2], [3, 4]]).determinant(),
+            Err(Error::NotRepresentable)
+        );
+        assert_eq!(
+            HeapMatrix::<u8>::new_owned_2d([[1, 2, 3], [2, 4, 6], [3, 1, 0]]).rank(),
+            2
+        );
+
+        let permutation: StackMatrix<u32, 3, 3> =
+            StackMatrix::new([[0, 1, 0], [0, 0, 1], [1, 0, 0]]);
+        assert_eq!(permutation.determinant(), Ok(1));
+        assert_eq!(permutation.try_inverse(), Ok(permutation.transpose()));
+        assert_eq!(mat.try_inverse(), Err(Error::NotRepresentable));
+    }
+
+    #[test]
+    fn heap_inverse_check() {
+        let mat = HeapMatrix::new_owned_2d([[4.0, 7.0], [2.0, 6.0]]);
+        let inverse = mat.try_inverse().unwrap();
+
+        assert_approx_eq(
+            &inverse,
+            &HeapMatrix::new_owned_2d([[0.6, -0.7], [-0.2, 0.4]]),
+        );
+        assert_approx_eq(
+            &mat.dot_prod(&inverse),
+            &HeapMatrix::new_owned_2d([[1.0, 0.0], [0.0, 1.0]]),
+        );
+    }
+
+    #[test]
+    fn heap_square_not_square_check() {
+        let mat = HeapMatrix::new_owned_2d([[1, 2, 3], [4, 5, 6]]);
+
+        assert_eq!(
+            mat.determinant(),
+            Err(Error::NotSquare { dimensions: (3, 2) })
+        );
+        assert!(mat.try_inverse().is_err());
+        assert_eq!(mat.rank(), 2);
+    }
+
+    #[test]
+    fn heap_rank_check() {
+        let mat = HeapMatrix::new_owned_2d([[1, 2, 3], [2, 4, 6], [1, 0, 1]]);
+
+        assert_eq!(mat.rank(), 2);
+        assert_eq!(HeapMatrix::new_owned_2d([[0, 0], [0, 0]]).rank(), 0);
+        assert_eq!(HeapMatrix::new_owned_2d([[1.0, 2.0], [3.0, 4.0]]).rank(), 2);
+    }
+
+    #[test]
+    fn heap_lu_solve_check() {
+        let mat = HeapMatrix::new_owned_2d([
+            [2.0, 1.0, 1.0],
+            [4.0, -6.0, 0.0],
+            [-2.0, 7.0, 2.0],
+        ]);
+        let lu = mat.lu().unwrap();
+
+        let x: Vec<f64> = lu.solve_vec(&[5.0, -2.0, 9.0]).unwrap();
+        for (x, expected) in x.iter().zip(&[1.0, 1.0, 2.0]) {
+            assert!((x - expected).abs() < 1e-9);
+        }
+
+        let b = HeapMatrix::new_owned_2d([[5.0, 4.0], [-2.0, -2.0], [9.0, 7.0]]);
+        assert_approx_eq(
+            &lu.solve(&b).unwrap(),
+            &HeapMatrix::new_owned_2d([[1.0, 1.0], [1.0, 1.0], [2.0, 1.0]]),
+        );
+        assert_eq!(
+            lu.solve_vec(&[1.0, 2.0]),
+            Err(Error::IncorrectLength {
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn heap_lu_determinant_inverse_check() {
+        let mat =
+            HeapMatrix::new_owned_2d([[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [3.0, 0.0, 1.0]]);
+        let lu = mat.lu().unwrap();
+
+        assert!((lu.determinant() - -5.0_f64).abs() < 1e-9);
+        assert_approx_eq(
+            &mat.dot_prod(&lu.inverse().unwrap()),
+            &HeapMatrix::new_owned_2d([
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 0.0, 1.0],
+            ]),
+        );
+        assert_eq!(lu.permutation(), &[2, 0, 1]);
+    }
+
+    #[test]
+    fn stack_lu_singular_check() {
+        let lu = StackMatrix::new([[1.0, 2.0], [2.0, 4.0]]).lu().unwrap();
+
+        assert!(lu.is_singular());
+        assert_eq!(lu.determinant(), 0.0);
+        assert_eq!(lu.solve_vec(&[1.0, 2.0]), Err(Error::Singular));
+        assert_eq!(lu.inverse(), Err(Error::Singular));
+    }
+
+    #[test]
+    fn heap_qr_check() {
+        let mat = HeapMatrix::new_owned_2d([
+            [12.0, -51.0, 4.0],
+            [6.0, 167.0, -68.0],
+            [-4.0, 24.0, -41.0],
+            [1.0, 2.0, 3.0],
+        ]);
+        let qr = mat.qr();
+
+        let q = qr.q();
+        let r = qr.r();
+        assert_eq!((q.get_x_len(), q.get_y_len()), (4, 4));
+        assert_eq!((r.get_x_len(), r.get_y_len()), (3, 4));
+        assert_approx_eq(&q.dot_prod(&r), &mat);
+
+        let thin_q = qr.thin_q();
+        let thin_r = qr.thin_r();
+        assert_eq!((thin_q.get_x_len(), thin_q.get_y_len()), (3, 4));
+        assert_eq!((thin_r.get_x_len(), thin_r.get_y_len()), (3, 3));
+        assert_approx_eq(&thin_q.dot_prod(&thin_r), &mat);
+
+        for y in 1..3 {
+            for x in 0..y {
+                assert_eq!(thin_r.get_at_unchecked(x, y), 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn heap_least_squares_check() {
+        // Fits y = 1 + 2x to points which lie exactly on the line, and then to
+        // points which do not.
+        let mat =
+            HeapMatrix::new_owned_2d([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+
+        let (x, residual) = mat.least_squares(&[1.0, 3.0, 5.0, 7.0]).unwrap();
+        assert!((x[0] - 1.0_f64).abs() < 1e-9 && (x[1] - 2.0_f64).abs() < 1e-9);
+        assert!(residual.abs() < 1e-9);
+
+        let (x, residual) = mat.least_squares(&[0.0, 1.0, 1.0, 2.0]).unwrap();
+        assert!((x[0] - 0.1_f64).abs() < 1e-9 && (x[1] - 0.6_f64).abs() < 1e-9);
+        assert!((residual - 0.2_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn stack_least_squares_rank_deficient_check() {
+        let mat = StackMatrix::new([[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]);
+
+        assert_eq!(mat.qr().rank(), 1);
+        assert_eq!(
+            mat.least_squares(&[1.0, 2.0, 3.0]),
+            Err(Error::RankDeficient {
+                rank: 1,
+                required: 2
+            })
+        );
+    }
+
+    #[test]
+    fn heap_cholesky_check() {
+        let mat = HeapMatrix::new_owned_2d([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]);
+        let cholesky = mat.cholesky().unwrap();
+
+        assert_approx_eq(
+            cholesky.l(),
+            &HeapMatrix::new_owned_2d([
+                [2.0, 0.0, 0.0],
+                [6.0, 1.0, 0.0],
+                [-8.0, 5.0, 3.0],
+            ]),
+        );
+        assert!((cholesky.determinant() - 36.0_f64).abs() < 1e-9);
+        assert!((cholesky.log_determinant() - 36.0_f64.ln()).abs() < 1e-9);
+
+        let x: Vec<f64> = cholesky.solve_vec(&[0.0, 6.0, 39.0]).unwrap();
+        for (x, expected) in x.iter().zip(&[1.0, 1.0, 1.0]) {
+            assert!((x - expected).abs() < 1e-9);
+        }
+
+        assert_approx_eq(
+            &mat.dot_prod(&cholesky.inverse()),
+            &HeapMatrix::new_owned_2d([
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 0.0, 1.0],
+            ]),
+        );
+    }
+
+    #[test]
+    fn stack_cholesky_not_positive_definite_check() {
+        let mat = StackMatrix::new([[1.0, 2.0], [2.0, 1.0]]);
+
+        assert_eq!(mat.cholesky(), Err(Error::NotPositiveDefinite));
+    }
+
+    #[test]
+    fn heap_symmetric_eigen_check() {
+        let mat = HeapMatrix::new_owned_2d([
+            [2.0, -1.0, 0.0],
+            [-1.0, 2.0, -1.0],
+            [0.0, -1.0, 2.0],
+        ]);
+        let eigen = mat.symmetric_eigen().unwrap();
+
+        let expected = [2.0 - 2.0_f64.sqrt(), 2.0, 2.0 + 2.0_f64.sqrt()];
+        for (value, expected) in eigen.eigenvalues().iter().zip(&expected) {
+            assert!((value - expected).abs() < 1e-9);
+        }
+
+        let vectors = eigen.eigenvectors();
+        for (i, value) in eigen.eigenvalues().iter().enumerate() {
+            let vector = HeapMatrix::new(
+                &(0..3)
+                    .map(|y| vectors.get_at_unchecked(i, y))
+                    .collect::<Vec<_>>(),
+                1,
+                3,
+            );
+            assert_approx_eq(&mat.dot_prod(&vector), &(vector.clone() * *value));
+        }
+
+        let mut identity = vectors.clone();
+        for y in 0..3 {
+            for x in 0..3 {
+                let mut cell = 0.0;
+                for k in 0..3 {
+                    cell +=
+                        vectors.get_at_unchecked(x, k) * vectors.get_at_unchecked(y, k);
+                }
+                identity.get_data_mut()[y * 3 + x] = cell;
+            }
+        }
+        assert_approx_eq(
+            &identity,
+            &HeapMatrix::new_owned_2d([
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 0.0, 1.0],
+            ]),
+        );
+    }
+
+    #[test]
+    fn stack_symmetric_eigen_check() {
+        let mat = StackMatrix::new([[2.0_f32, 1.0], [1.0, 2.0]]);
+        let eigen = mat.symmetric_eigen().unwrap();
+
+        assert!((eigen.eigenvalues()[0] - 1.0).abs() < 1e-6);
+        assert!((eigen.eigenvalues()[1] - 3.0).abs() < 1e-6);
+        assert_eq!(
+            SymmetricEigen::with_max_sweeps(&mat, 0),
+            Err(Error::NoConvergence { iterations: 0 })
+        );
+
+        // A single rotation diagonalises a 2 by 2 Matrix, and a diagonal Matrix
+        // needs no sweeps at all.
+        let mat = StackMatrix::new([[2.0_f64, 1.0], [1.0, 2.0]]);
+        assert!(SymmetricEigen::with_max_sweeps(&mat, 1).is_ok());
+        let diagonal = StackMatrix::new([[2.0_f64, 0.0], [0.0, 1.0]]);
+        assert!(SymmetricEigen::with_max_sweeps(&diagonal, 0).is_ok());
+    }
+
+    #[test]
+    fn heap_svd_check() {
+        let mat = HeapMatrix::new_owned_2d([[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]]);
+        let svd = mat.svd().unwrap();
+
+        assert!((svd.singular_values()[0] - 5.0_f64).abs() < 1e-9);
+        assert!((svd.singular_values()[1] - 3.0_f64).abs() < 1e-9);
+        assert_eq!(svd.rank(), 2);
+        assert!((svd.condition_number() - 5.0 / 3.0).abs() < 1e-9);
+
+        let mut s = HeapMatrix::new_owned_2d([[0.0, 0.0], [0.0, 0.0]]);
+        s.get_data_mut()[0] = svd.singular_values()[0];
+        s.get_data_mut()[3] = svd.singular_values()[1];
+        assert_approx_eq(&svd.u().dot_prod(&s).dot_prod(svd.v_t()), &mat);
+
+        let tall = HeapMatrix::new_owned_2d([[3.0, 2.0], [2.0, 3.0], [2.0, -2.0]]);
+        let svd = tall.svd().unwrap();
+        assert_approx_eq(&svd.u().dot_prod(&s).dot_prod(svd.v_t()), &tall);
+
+        // A single rotation orthogonalises the columns of a 2 by 2 Matrix, and
+        // orthogonal columns need no sweeps at all.
+        let mat = StackMatrix::new([[3.0_f64, 1.0], [1.0, 3.0]]);
+        assert!(SingularValueDecomposition::with_max_sweeps(&mat, 1).is_ok());
+        assert_eq!(
+            SingularValueDecomposition::with_max_sweeps(&mat, 0),
+            Err(Error::NoConvergence { iterations: 0 })
+        );
+        let orthogonal = StackMatrix::new([[1.0_f64, -2.0], [2.0, 1.0]]);
+        assert!(SingularValueDecomposition::with_max_sweeps(&orthogonal, 0).is_ok());
+    }
+
+    #[test]
+    fn heap_pseudo_inverse_check() {
+        let mat = HeapMatrix::new_owned_2d([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let pseudo_inverse: HeapMatrix<f64> = mat.pseudo_inverse().unwrap();
+
+        assert_eq!(
+            (pseudo_inverse.get_x_len(), pseudo_inverse.get_y_len()),
+            (3, 2)
+        );
+        assert_approx_eq(
+            &pseudo_inverse.dot_prod(&mat),
+            &HeapMatrix::new_owned_2d([[1.0, 0.0], [0.0, 1.0]]),
+        );
+
+        let singular = HeapMatrix::new_owned_2d([[1.0, 2.0], [2.0, 4.0]]);
+        let pseudo_inverse: HeapMatrix<f64> = singular.pseudo_inverse().unwrap();
+        assert_approx_eq(
+            &pseudo_inverse,
+            &HeapMatrix::new_owned_2d([[0.04, 0.08], [0.08, 0.16]]),
+        );
+        assert_eq!(singular.numerical_rank(1e-9), Ok(1));
+    }
+
+    #[test]
+    fn stack_svd_check() {
+        let mat = StackMatrix::new([[2.0, 0.0], [0.0, -3.0]]);
+
+        assert!((mat.norm_2().unwrap() - 3.0_f64).abs() < 1e-9);
+        assert!((mat.condition_number().unwrap() - 1.5_f64).abs() < 1e-9);
+
+        let pseudo_inverse: StackMatrix<f64, 2, 2> = mat.pseudo_inverse().unwrap();
+        assert_approx_eq(
+            &pseudo_inverse,
+            &StackMatrix::new([[0.5, 0.0], [0.0, -1.0 / 3.0]]),
+        );
+    }
+
+    #[test]
+    fn heap_eigen_check() {
+        // The companion matrix of (x - 2)(x^2 + 1).
+        let mat = HeapMatrix::new_owned_2d([
+            [2.0, -1.0, 2.0],
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+        ]);
+        let eigen = mat.eigen().unwrap();
+
+        let expected = [
+            Complex::new(2.0, 0.0),
+            Complex::new(0.0, 1.0),
+            Complex::new(0.0, -1.0),
+        ];
+        for (value, expected) in eigen.eigenvalues().iter().zip(&expected) {
+            assert!(
+                (*value - *expected).norm() < 1e-9_f64,
+                "{} != {}",
+                value,
+                expected
+            );
+        }
+
+        let complex_mat = HeapMatrix::new(
+            &mat.get_data()
+                .iter()
+                .map(|x| Complex::from(*x))
+                .collect::<Vec<_>>(),
+            3,
+            3,
+        );
+        let vectors = eigen.eigenvectors();
+        for (i, value) in eigen.eigenvalues().iter().enumerate() {
+            let vector = HeapMatrix::new(
+                &(0..3)
+                    .map(|y| vectors.get_at_unchecked(i, y))
+                    .collect::<Vec<_>>(),
+                1,
+                3,
+            );
+            let residual = complex_mat.dot_prod(&vector) - vector.clone() * *value;
+
+            assert!(residual.get_data().iter().all(|x| x.norm() < 1e-6));
+        }
+    }
+
+    #[test]
+    fn stack_eigen_check() {
+        // The companion matrix of (x - 1)(x - 2)(x - 3).
+        let mat = StackMatrix::new([[6.0, -11.0, 6.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+        let eigen = mat.eigen().unwrap();
+
+        for (value, expected) in eigen.eigenvalues().iter().zip(&[3.0, 2.0, 1.0]) {
+            assert!((value.re - expected).abs() < 1e-9_f64);
+            assert_eq!(value.im, 0.0);
+        }
+
+        let rotation = StackMatrix::new([[0.0, -1.0], [1.0, 0.0]]);
+        assert_eq!(
+            rotation.eigen().unwrap().eigenvalues(),
+            &[Complex::new(0.0, 1.0), Complex::new(0.0, -1.0)]
+        );
+    }
+
+    #[test]
+    fn complex_arithmetic_check() {
+        let a = Complex::new(1.0, 2.0);
+        let b = Complex::new(3.0, -1.0);
+
+        assert_eq!(a + b, Complex::new(4.0, 1.0));
+        assert_eq!(a - b, Complex::new(-2.0, 3.0));
+        assert_eq!(a * b, Complex::new(5.0, 5.0));
+        assert_eq!((a * b) / b, a);
+        assert_eq!(a.conj(), Complex::new(1.0, -2.0));
+        assert_eq!(Complex::new(3.0, 4.0).norm(), 5.0);
+        assert_eq!(format!("{} {}", a, b), "1+2i 3-1i");
+    }
+
+    #[test]
+    fn heap_complex_check() {
+        let mat = HeapMatrix::new_owned_2d([
+            [Complex::new(1, 1), Complex::new(2, 0), Complex::new(0, -3)],
+            [Complex::new(0, 0), Complex::new(4, -2), Complex::new(1, 1)],
+        ]);
+
+        assert_eq!(
+            mat.conjugate(),
+            HeapMatrix::new_owned_2d([
+                [Complex::new(1, -1), Complex::new(2, 0), Complex::new(0, 3)],
+                [Complex::new(0, 0), Complex::new(4, 2), Complex::new(1, -1)],
+            ])
+        );
+
+        let adjoint: HeapMatrix<Complex<i32>> = mat.adjoint();
+        assert_eq!(
+            adjoint,
+            HeapMatrix::new_owned_2d([
+                [Complex::new(1, -1), Complex::new(0, 0)],
+                [Complex::new(2, 0), Complex::new(4, 2)],
+                [Complex::new(0, 3), Complex::new(1, -1)],
+            ])
+        );
+        assert!(mat.dot_prod(&adjoint).is_hermitian());
+        assert!(!mat.is_hermitian());
+    }
+
+    #[test]
+    fn stack_complex_check() {
+        let hermitian = StackMatrix::new([
+            [Complex::new(2.0, 0.0), Complex::new(1.0, -1.0)],
+            [Complex::new(1.0, 1.0), Complex::new(3.0, 0.0)],
+        ]);
+
+        assert!(hermitian.is_hermitian());
+        assert!(!(hermitian * Complex::new(0.0, 1.0)).is_hermitian());
+
+        let adjoint: StackMatrix<Complex<f64>, 2, 2> = hermitian.adjoint();
+        assert_eq!(adjoint, hermitian);
+    }
+
+    /// A boolean semiring which only implements what matrix products need, so
+    /// it has no subtraction, division or Display.
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    struct Reachable(bool);
+
+    impl Zero for Reachable {
+        fn zero() -> Self { Self(false) }
+        fn is_zero(&self) -> bool { !self.0 }
+    }
+
+    impl One for Reachable {
+        fn one() -> Self { Self(true) }
+    }
+
+    impl std::ops::Add for Reachable {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output { Self(self.0 || rhs.0) }
+    }
+
+    impl std::ops::Mul for Reachable {
+        type Output = Self;
+
+        fn mul(self, rhs: Self) -> Self::Output { Self(self.0 && rhs.0) }
+    }
+
+    impl std::ops::AddAssign for Reachable {
+        fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+    }
+
+    impl std::ops::MulAssign for Reachable {
+        fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+    }
+
+    #[test]
+    fn semiring_dot_prod_check() {
+        let (t, f) = (Reachable(true), Reachable(false));
+        let edges = StackMatrix::new([[f, t, f], [f, f, t], [f, f, f]]);
+
+        let two_steps = edges.dot_prod(&edges);
+        assert_eq!(
+            two_steps,
+            StackMatrix::new([[f, f, t], [f, f, f], [f, f, f]])
+        );
+        assert_eq!(two_steps.trace(), Ok(f));
+
+        let heap = HeapMatrix::new_owned_2d([[f, t, f], [f, f, t], [f, f, f]]);
+        let heap_two_steps: HeapMatrix<Reachable> = heap.mat_mul(&heap);
+        assert_eq!(heap_two_steps, two_steps);
+        assert_eq!((heap.clone() + heap).get_data(), edges.get_data());
+    }
+
+    #[test]
+    fn identities_check() {
+        assert_eq!(i32::zero(), 0);
+        assert_eq!(f64::one(), 1.0);
+        assert!(0u8.is_zero());
+        assert_eq!(Complex::<i32>::one(), Complex::new(1, 0));
+        assert!(Complex::new(0.0, 0.0).is_zero());
+        assert!(!Complex::new(0.0, 1.0).is_zero());
+    }
+
+    fn hilbert(n: usize) -> HeapMatrix<Ratio<i64>> {
+        let data: Vec<Ratio<i64>> = (0..n * n)
+            .map(|i| Ratio::new(1, (i / n + i % n + 1) as i64))
+            .collect();
+
+        HeapMatrix::new(&data, n, n)
+    }
+
+    #[test]
+    fn ratio_arithmetic_check() {
+        let half = Ratio::new(2, 4);
+        assert_eq!((half.numer(), half.denom()), (1, 2));
+        assert_eq!(Ratio::new(3, -6), Ratio::new(-1, 2));
+        assert_eq!(half + Ratio::new(1, 3), Ratio::new(5, 6));
+        assert_eq!(half - Ratio::new(1, 3), Ratio::new(1, 6));
+        assert_eq!(half * Ratio::new(2, 3), Ratio::new(1, 3));
+        assert_eq!(half / Ratio::new(1, 4), Ratio::from_integer(2));
+        assert!(Ratio::new(-1, 2) < Ratio::new(1, 3));
+        assert!(Ratio::<i64>::zero().is_zero());
+        assert_eq!(
+            format!("{} {}", Ratio::new(6, 3), Ratio::new(-1, 3)),
+            "2 -1/3"
+        );
+    }
+
+    #[test]
+    fn ratio_determinant_check() {
+        assert_eq!(hilbert(4).determinant(), Ok(Ratio::new(1, 6_048_000)));
+        assert_eq!(
+            hilbert(4).lu().unwrap().determinant(),
+            Ratio::new(1, 6_048_000)
+        );
+    }
+
+    #[test]
+    fn ratio_inverse_check() {
+        let inverse = hilbert(4).try_inverse().unwrap();
+        let expected: Vec<Ratio<i64>> = [
+            16, -120, 240, -140, -120, 1200, -2700, 1680, 240, -2700, 6480, -4200, -140,
+            1680, -4200, 2800,
+        ]
+        .iter()
+        .map(|x| Ratio::from_integer(*x))
+        .collect();
+        assert_eq!(inverse, HeapMatrix::new(&expected, 4, 4));
+
+        let identity: HeapMatrix<Ratio<i64>> = hilbert(4).mat_mul(&inverse);
+        for y in 0..4 {
+            for x in 0..4 {
+                let expected = if x == y { Ratio::one() } else { Ratio::zero() };
+                assert_eq!(identity.get_at_unchecked(x, y), expected);
+            }
+        }
+
+        assert_eq!(hilbert(4).lu().unwrap().inverse(), Ok(inverse));
+    }
+
+    #[test]
+    fn ratio_solve_check() {
+        let mat = HeapMatrix::new_owned_2d([
+            [Ratio::from_integer(2), Ratio::from_integer(1)],
+            [Ratio::from_integer(1), Ratio::from_integer(3)],
+        ]);
+        let b = [Ratio::from_integer(1), Ratio::from_integer(1)];
+
+        assert_eq!(
+            mat.lu().unwrap().solve_vec(&b),
+            Ok(vec![Ratio::new(2, 5), Ratio::new(1, 5)])
+        );
+    }
+
+    type F7 = ModP<7>;
+
+    fn modp<const P: u64, const X: usize, const Y: usize>(
+        data: [[i64; X]; Y],
+    ) -> StackMatrix<ModP<P>, X, Y>
+    where
+        [ModP<P>; X * Y]: Sized,
+    {
+        let mut elements = [[ModP::zero(); X]; Y];
+        for (row, values) in elements.iter_mut().zip(data.iter()) {
+            for (element, &value) in row.iter_mut().zip(values.iter()) {
+                *element = ModP::from_i64(value);
+            }
+        }
+
+        StackMatrix::new(elements)
+    }
+
+    fn gf7<const X: usize, const Y: usize>(data: [[i64; X]; Y]) -> StackMatrix<F7, X, Y>
+    where
+        [F7; X * Y]: Sized,
+    {
+        modp(data)
+    }
+
+    #[test]
+    fn modp_arithmetic_check() {
+        assert_eq!(F7::new(5) + F7::new(4), F7::new(2));
+        assert_eq!(F7::new(2) - F7::new(5), F7::new(4));
+        assert_eq!(F7::new(3) * F7::new(5), F7::new(1));
+        assert_eq!(F7::new(1) / F7::new(3), F7::new(5));
+        assert_eq!(F7::from_i64(-1), F7::new(6));
+        assert_eq!(F7::new(3).pow(6), F7::one());
+        assert_eq!(F7::zero().inverse(), None);
+        assert_eq!(ModP::<8>::new(4).inverse(), None);
+        assert_eq!(ModP::<8>::new(3).inverse(), Some(ModP::new(3)));
+
+        let large = ModP::<{ u64::MAX - 58 }>::new(u64::MAX - 59);
+        assert_eq!(large * large, ModP::one());
+        assert_eq!(large + large, ModP::new(u64::MAX - 60));
+        assert_eq!(
+            ModP::<{ u64::MAX - 58 }>::from_i64(-1),
+            ModP::new(u64::MAX - 59)
+        );
+        assert_eq!(
+            ModP::<{ u64::MAX - 58 }>::from_i64(i64::MIN),
+            ModP::new(u64::MAX - 58 - (1 << 63))
+        );
+    }
+
+    #[test]
+    fn modp_reduced_row_echelon_check() {
+        let mat = gf7([[1, 2, 3, 4], [2, 4, 6, 2], [1, 2, 4, 0]]);
+
+        assert_eq!(
+            mat.reduced_row_echelon(),
+            Ok(gf7([[1, 2, 0, 0], [0, 0, 1, 0], [0, 0, 0, 1]]))
+        );
+        assert_eq!(mat.rank(), 3);
+        assert_eq!(gf7([[1, 2, 3], [2, 4, 6]]).rank(), 1);
+    }
+
+    #[test]
+    fn modp_inverse_check() {
+        let mat = gf7([[1, 2], [3, 4]]);
+        let inverse = mat.try_inverse().unwrap();
+
+        assert_eq!(inverse, gf7([[5, 1], [5, 3]]));
+        assert_eq!(mat * inverse, gf7([[1, 0], [0, 1]]));
+        assert_eq!(mat.determinant(), Ok(F7::from_i64(-2)));
+        assert_eq!(gf7([[1, 2], [2, 4]]).try_inverse(), Err(Error::Singular));
+
+        let gf2: StackMatrix<ModP<2>, 2, 2> = modp([[1, 1], [0, 1]]);
+        assert_eq!(gf2.try_inverse(), Ok(gf2));
+
+        let z4: StackMatrix<ModP<4>, 2, 2> = modp([[1, 1], [0, 1]]);
+        assert_eq!(z4.try_inverse(), Ok(modp([[1, 3], [0, 1]])));
+    }
+
+    #[test]
+    fn modp_null_space_check() {
+        let mat = gf7([[1, 2, 3, 4], [2, 4, 6, 2]]);
+        let null_space = mat.null_space().unwrap();
+
+        assert_eq!((null_space.get_x_len(), null_space.get_y_len()), (2, 4));
+        assert_eq!(null_space.rank(), 2);
+
+        let product: HeapMatrix<F7> = mat.mat_mul(&null_space);
+        assert!(product.get_data().iter().all(Zero::is_zero));
+
+        let heap = HeapMatrix::new_owned_2d([
+            [F7::new(1), F7::new(2)],
+            [F7::new(3), F7::new(4)],
+        ]);
+        assert_eq!(heap.null_space().unwrap().get_x_len(), 0);
+    }
+
+    #[test]
+    fn inexact_reduced_row_echelon_check() {
+        let unsigned = StackMatrix::<u32, 2, 2>::new([[1, 2], [3, 4]]);
+        assert_eq!(unsigned.reduced_row_echelon(), Err(Error::NotRepresentable));
+        assert_eq!(unsigned.null_space(), Err(Error::NotRepresentable));
+        assert_eq!(unsigned.rank(), 2);
+
+        let signed = HeapMatrix::new_owned_2d([[1, 2], [3, 4]]);
+        assert_eq!(signed.reduced_row_echelon(), Err(Error::NotRepresentable));
+
+        let rational = HeapMatrix::new_owned_2d([
+            [Ratio::from_integer(1), Ratio::from_integer(2)],
+            [Ratio::from_integer(2), Ratio::from_integer(4)],
+        ]);
+        assert_eq!(rational.null_space().unwrap().get_x_len(), 1);
+    }
+
+    #[test]
+    fn stack_transpose_check() {
+        let mat = StackMatrix::new([[1, 2, 3], [4, 5, 6]]);
+        let transposed: StackMatrix<i32, 2, 3> = mat.transpose();
+
+        assert_eq!(transposed, StackMatrix::new([[1, 4], [2, 5], [3, 6]]));
+        assert_eq!(transposed.transpose(), mat);
+    }
+
+    #[test]
+    fn heap_transpose_check() {
+        let mat = HeapMatrix::new_owned_2d([[1, 2, 3], [4, 5, 6]]);
+        let expected = HeapMatrix::new_owned_2d([[1, 4], [2, 5], [3, 6]]);
+        assert_eq!(mat.transpose(), expected);
+
+        let mut in_place = mat.clone();
+        in_place.transpose_mut();
+        assert_eq!(in_place, expected);
+        in_place.transpose_mut();
+        assert_eq!(in_place, mat);
+    }
+
+    #[test]
+    fn heap_transpose_large_check() {
+        for (columns, rows) in [(70, 70), (70, 45), (1, 33), (33, 1)] {
+            let data: Vec<usize> = (0..columns * rows).collect();
+            let mat = HeapMatrix::new(&data, columns, rows);
+
+            let mut in_place = mat.clone();
+            in_place.transpose_mut();
+            assert_eq!(in_place, mat.transpose());
+            assert_eq!(
+                (in_place.get_x_len(), in_place.get_y_len()),
+                (rows, columns)
+            );
+
+            for y in 0..rows {
+                for x in 0..columns {
+                    assert_eq!(
+                        in_place.get_at_unchecked(y, x),
+                        mat.get_at_unchecked(x, y)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn transposed_view_check() {
+        let mat = StackMatrix::new([[1, 2, 3], [4, 5, 6]]);
+        let view = mat.transposed();
+
+        assert_eq!((view.get_x_len(), view.get_y_len()), (2, 3));
+        assert_eq!(view.get_at(1, 2), Ok(6));
+        assert_eq!(view.get_at_unchecked(0, 1), 2);
+        assert_eq!(
+            view.get_at(2, 0),
+            Err(Error::OutOfRange {
+                index: (2, 0),
+                bounds: (2, 3)
+            })
+        );
+        assert_eq!(view.to_heap_matrix(), mat.transpose());
+        assert_eq!(
+            mat.mat_mul::<_, HeapMatrix<i32>>(&view),
+            mat.mat_mul::<_, HeapMatrix<i32>>(&mat.transpose())
+        );
+
+        let mut heap = HeapMatrix::new_owned_2d([[1, 2, 3], [4, 5, 6]]);
+        *heap.transposed_mut().get_at_mut(1, 2).unwrap() = 0;
+        heap.transposed_mut()
+            .row_mut(0)
+            .for_each(|cell| *cell *= 10);
+        assert_eq!(heap, HeapMatrix::new_owned_2d([[10, 2, 3], [40, 5, 0]]));
+    }
+
+    #[test]
+    fn get_row_at_check() {
+        let mat = HeapMatrix::new_owned_2d([[1, 2], [3, 4], [5, 6]]);
+
+        assert_eq!(mat.get_row_at(2), &[5, 6]);
+        assert_eq!(mat.diagonal_view().get_row_at(1), &[4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_row_at_non_adjacent_check() {
+        let mat = HeapMatrix::new_owned_2d([[1, 2], [3, 4]]);
+        mat.transposed().get_row_at(0);
+    }
+
+    #[test]
+    fn matrix_view_check() {
+        let mat = HeapMatrix::new_owned_2d([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+        let view = mat.view(1, 1, 2, 2).unwrap();
+
+        assert_eq!(view, HeapMatrix::new_owned_2d([[6, 7], [10, 11]]));
+        assert_eq!(view.get_row_at(1), &[10, 11]);
+        assert_eq!(view.last(), Some(&11));
+        assert_eq!(view.view(1, 0, 1, 2).unwrap().to_vec(), vec![7, 11]);
+        assert_eq!(view.transposed().to_vec(), vec![6, 10, 7, 11]);
+        assert_eq!(
+            mat.view(3, 1, 2, 2).unwrap_err(),
+            Error::OutOfRange {
+                index: (5, 3),
+                bounds: (4, 3)
+            }
+        );
+
+        assert_eq!(mat.column_view(2).unwrap().to_vec(), vec![3, 7, 11]);
+        assert_eq!(mat.row_view(1).unwrap().to_vec(), vec![5, 6, 7, 8]);
+        assert_eq!(mat.diagonal_view().to_vec(), vec![1, 6, 11]);
+        assert_eq!(mat.transposed().diagonal_view().to_vec(), vec![1, 6, 11]);
+    }
+
+    #[test]
+    fn matrix_view_operations_check() {
+        let mat = StackMatrix::new([[4.0, 3.0, 9.0], [6.0, 3.0, 9.0], [1.0, 1.0, 1.0]]);
+        let block = mat.view(0, 0, 2, 2).unwrap();
+
+        let product: HeapMatrix<f64> =
+            HeapMatrix::new_owned_2d([[1.0, 0.0], [0.0, 1.0]]).mat_mul(&block);
+        assert_eq!(product, block.to_heap_matrix());
+        assert_approx_eq(
+            &LuDecomposition::new(&block).unwrap().inverse().unwrap(),
+            &HeapMatrix::new_owned_2d([[-0.5, 0.5], [1.0, -2.0 / 3.0]]),
+        );
+
+        let sum = HeapMatrix::new_owned_2d([[1.0, 1.0], [1.0, 1.0]])
+            + mat.transposed().view(1, 1, 2, 2).unwrap();
+        assert_eq!(sum, HeapMatrix::new_owned_2d([[4.0, 2.0], [10.0, 2.0]]));
+    }
+
+    #[test]
+    fn matrix_view_mut_check() {
+        let mut mat = HeapMatrix::new_owned_2d([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+
+        *mat.view_mut(1, 1, 2, 2).unwrap().get_at_mut(1, 0).unwrap() = 0;
+        assert_eq!(mat.get_at(2, 1), Ok(0));
+
+        mat.view_mut(0, 1, 2, 2)
+            .unwrap()
+            .copy_from(&StackMatrix::new([[10, 11], [12, 13]]))
+            .unwrap();
+        assert_eq!(
+            mat,
+            HeapMatrix::new_owned_2d([[1, 2, 3], [10, 11, 0], [12, 13, 9]])
+        );
+
+        let mut diagonal = mat.diagonal_view_mut();
+        for y in 0..3 {
+            *diagonal.get_at_mut(0, y).unwrap() = -1;
+        }
+        assert_eq!(mat.diagonal_view().to_vec(), vec![-1, -1, -1]);
+
+        let mut column = mat.column_view_mut(2).unwrap();
+        assert_eq!(
+            column.copy_from(&StackMatrix::new([[1, 2]])),
+            Err(Error::DimensionMismatch {
+                expected: (1, 3),
+                found: (2, 1)
+            })
+        );
+        *column.last_mut().unwrap() = 5;
+        assert_eq!(column.as_view().to_vec(), vec![3, 0, 5]);
+        mat.row_view_mut(0).unwrap().get_row_at_mut(0).fill(0);
+        assert_eq!(mat.get_row_at(0), &[0, 0, 0]);
+    }
+
+    #[test]
+    fn matrix_view_mut_strides_check() {
+        let mut data = [1, 2, 3, 4, 5, 6];
+
+        // The columns are each in their own range of the data.
+        let mut view = MatrixViewMut::new(&mut data, 2, 2, 3, 1);
+        *view.get_at_mut(1, 1).unwrap() = 0;
+        assert_eq!(view.as_view().to_vec(), vec![1, 4, 2, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "must not overlap")]
+    fn matrix_view_mut_interleaved_strides_check() {
+        let mut data = [0; 15];
+
+        // No two of the elements at x * 3 + y * 4 share an index, but neither
+        // the rows nor the columns lie in separate ranges of the data.
+        MatrixViewMut::new(&mut data, 3, 3, 3, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "must not overlap")]
+    fn matrix_view_mut_overlapping_strides_check() {
+        let mut data = [1, 2, 3, 4];
+
+        // The elements at (1, 0) and (0, 1) would both be at index 1.
+        MatrixViewMut::new(&mut data, 2, 2, 1, 1);
+    }
+
+    #[test]
+    fn row_and_column_iterators_check() {
+        let stack = StackMatrix::new([[1, 2, 3], [4, 5, 6]]);
+        let heap = HeapMatrix::new_owned_2d([[1, 2, 3], [4, 5, 6]]);
+
+        assert_eq!(stack.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(heap.column(2).copied().collect::<Vec<_>>(), vec![3, 6]);
+        assert_eq!(heap.row(1).copied().collect::<Vec<_>>(), vec![4, 5, 6]);
+        assert_eq!(heap.column(0).len(), 2);
+
+        let columns: Vec<Vec<i32>> =
+            stack.columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(columns, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+        fn row_sums<'a>(mat: impl DenseMatrixRef<'a, i32>) -> Vec<i32> {
+            mat.rows().map(|r| r.sum()).collect()
+        }
+        assert_eq!(row_sums(stack), vec![6, 15]);
+        assert_eq!(row_sums(&heap), vec![6, 15]);
+        assert_eq!(heap.rows().len(), 2);
+        assert_eq!(
+            heap.columns()
+                .next_back()
+                .unwrap()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![3, 6]
+        );
+
+        let transposed_rows: Vec<Vec<i32>> = heap
+            .transposed()
+            .rows()
+            .map(|r| r.copied().collect())
+            .collect();
+        assert_eq!(transposed_rows, columns);
+        assert_eq!(
+            heap.diagonal_view().column(0).copied().collect::<Vec<_>>(),
+            vec![1, 5]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "A 3x3 StackMatrix cannot hold a 2x2 Matrix")]
+    fn stack_mis_shaped_result_check() {
+        let mat = StackMatrix::new([[1, 2], [3, 4]]);
+        let _: StackMatrix<i32, 3, 3> = mat.mat_mul(&mat);
+    }
+
+    #[test]
+    #[should_panic]
+    fn column_out_of_range_check() { StackMatrix::new([[1, 2], [3, 4]]).column(2); }
+
+    #[test]
+    fn mutable_row_and_column_iterators_check() {
+        let mut stack = StackMatrix::new([[1, 2, 3], [4, 5, 6]]);
+        let mut heap = HeapMatrix::new_owned_2d([[1, 2, 3], [4, 5, 6]]);
+
+        stack.column_mut(0).for_each(|x| *x = 0);
+        heap.row_mut(1).for_each(|x| *x *= 10);
+        assert_eq!(stack, StackMatrix::new([[0, 2, 3], [0, 5, 6]]));
+        assert_eq!(heap, HeapMatrix::new_owned_2d([[1, 2, 3], [40, 50, 60]]));
+
+        for (x, column) in heap.columns_mut().enumerate() {
+            for cell in column {
+                *cell += x as i32;
+            }
+        }
+        assert_eq!(heap, HeapMatrix::new_owned_2d([[1, 3, 5], [40, 51, 62]]));
+
+        for row in stack.rows_mut() {
+            row.rev().fold(0, |sum, x| {
+                *x += sum;
+                *x
+            });
+        }
+        assert_eq!(stack, StackMatrix::new([[5, 5, 3], [11, 11, 6]]));
+
+        let mut view = heap.view_mut(1, 0, 2, 2).unwrap();
+        view.columns_mut().flatten().for_each(|x| *x = -*x);
+        assert_eq!(
+            heap,
+            HeapMatrix::new_owned_2d([[1, -3, -5], [40, -51, -62]])
+        );
+    }
+
+    #[test]
+    fn mutable_lanes_from_both_ends_check() {
+        let mut heap = HeapMatrix::new_owned_2d([[1, 2, 3], [4, 5, 6]]);
+
+        // The columns of a row-major Matrix interleave, and its rows do not.
+        let mut columns = heap.columns_mut();
+        columns.next_back().unwrap().for_each(|x| *x *= 10);
+        columns.next().unwrap().rev().for_each(|x| *x += 1);
+        assert_eq!(columns.len(), 1);
+        drop(columns);
+        let mut rows = heap.rows_mut();
+        rows.next_back().unwrap().for_each(|x| *x = -*x);
+        assert_eq!(rows.next().unwrap().len(), 3);
+        assert!(rows.next().is_none());
+        assert_eq!(heap, HeapMatrix::new_owned_2d([[2, 2, 30], [-5, -5, -60]]));
+
+        let mut column_major = heap.to_layout(Layout::ColumnMajor);
+        for (y, row) in column_major.rows_mut().rev().enumerate() {
+            row.for_each(|x| *x += y as i32);
+        }
+        column_major
+            .columns_mut()
+            .nth(1)
+            .unwrap()
+            .for_each(|x| *x = 0);
+        assert_eq!(
+            column_major,
+            HeapMatrix::new_owned_2d([[3, 0, 31], [-5, 0, -60]])
+        );
+
+        let mut empty = HeapMatrix::<i32>::new(&[], 3, 0);
+        assert_eq!(empty.columns_mut().count(), 3);
+        assert_eq!(
+            empty
+                .columns_mut()
+                .rev()
+                .map(Iterator::count)
+                .sum::<usize>(),
+            0
+        );
+    }
+
+    #[test]
+    fn heap_insert_check() {
+        let mut mat = HeapMatrix::new_owned_2d([[1, 2], [5, 6]]);
+
+        mat.insert_row_at(1, &[3, 4][..]).unwrap();
+        mat.insert_row_at(3, vec![7, 8]).unwrap();
+        assert_eq!(
+            mat,
+            HeapMatrix::new_owned_2d([[1, 2], [3, 4], [5, 6], [7, 8]])
+        );
+
+        mat.insert_column(0, (0..4).map(|x| x * 10)).unwrap();
+        mat.insert_column(3, [0; 4]).unwrap();
+        assert_eq!(
+            mat,
+            HeapMatrix::new_owned_2d([
+                [0, 1, 2, 0],
+                [10, 3, 4, 0],
+                [20, 5, 6, 0],
+                [30, 7, 8, 0]
+            ])
+        );
+
+        assert_eq!(
+            mat.insert_row_at(0, [1, 2]),
+            Err(Error::IncorrectLength {
+                expected: 4,
+                found: 2
+            })
+        );
+        assert_eq!(
+            mat.insert_column(5, [1, 2, 3, 4]),
+            Err(Error::OutOfRange {
+                index: (5, 0),
+                bounds: (4, 4)
+            })
+        );
+
+        let mut empty: HeapMatrix<i32> = HeapMatrix::new(&[], 0, 0);
+        empty.insert_column(0, &[1, 2][..]).unwrap();
+        empty.insert_row_at(2, [3]).unwrap();
+        assert_eq!(empty, HeapMatrix::new_owned_2d([[1], [2], [3]]));
+    }
+
+    #[test]
+    fn heap_remove_check() {
+        let mut mat = HeapMatrix::new_owned_2d([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+
+        assert_eq!(mat.remove_row(1), Ok(vec![4, 5, 6]));
+        assert_eq!(mat.remove_column(0), Ok(vec![1, 7]));
+        assert_eq!(mat, HeapMatrix::new_owned_2d([[2, 3], [8, 9]]));
+        assert_eq!(
+            mat.remove_row(2),
+            Err(Error::OutOfRange {
+                index: (0, 2),
+                bounds: (2, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn heap_swap_check() {
+        let mut mat = HeapMatrix::new_owned_2d([[1, 2, 3], [4, 5, 6]]);
+
+        mat.swap_rows(0, 1).unwrap();
+        mat.swap_columns(0, 2).unwrap();
+        assert_eq!(mat, HeapMatrix::new_owned_2d([[6, 5, 4], [3, 2, 1]]));
+        assert!(mat.swap_rows(0, 2).is_err());
+        assert!(mat.swap_columns(3, 0).is_err());
+    }
+
+    #[test]
+    fn heap_truncate_and_resize_check() {
+        let mut mat = HeapMatrix::new_owned_2d([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+
+        mat.truncate(2, 5);
+        assert_eq!(mat, HeapMatrix::new_owned_2d([[1, 2], [4, 5], [7, 8]]));
+
+        mat.resize(3, 2, 0);
+        assert_eq!(mat, HeapMatrix::new_owned_2d([[1, 2, 0], [4, 5, 0]]));
+
+        mat.resize(3, 3, -1);
+        assert_eq!(
+            mat,
+            HeapMatrix::new_owned_2d([[1, 2, 0], [4, 5, 0], [-1, -1, -1]])
+        );
+
+        mat.truncate(0, 0);
+        assert_eq!((mat.get_x_len(), mat.get_y_len()), (0, 0));
+        assert!(mat.get_data().is_empty());
+    }
+
+    #[test]
+    fn stack_concatenation_check() {
+        let a = StackMatrix::new([[1, 2], [3, 4]]);
+        let b = StackMatrix::new([[5], [6]]);
+
+        let augmented: StackMatrix<i32, 3, 2> = a.hstack(&b).unwrap();
+        assert_eq!(augmented, StackMatrix::new([[1, 2, 5], [3, 4, 6]]));
+
+        let stacked: StackMatrix<i32, 2, 3> =
+            a.vstack(&StackMatrix::new([[7, 8]])).unwrap();
+        assert_eq!(stacked, StackMatrix::new([[1, 2], [3, 4], [7, 8]]));
+    }
+
+    #[test]
+    fn mixed_concatenation_check() {
+        let stack = StackMatrix::new([[1, 2], [3, 4]]);
+        let heap = HeapMatrix::new_owned_2d([[5], [6]]);
+
+        assert_eq!(
+            stack.hstack(&heap),
+            Ok(HeapMatrix::new_owned_2d([[1, 2, 5], [3, 4, 6]]))
+        );
+        assert_eq!(
+            heap.hstack(&stack),
+            Ok(HeapMatrix::new_owned_2d([[5, 1, 2], [6, 3, 4]]))
+        );
+        assert_eq!(
+            heap.transposed()
+                .to_heap_matrix()
+                .vstack(&stack.row_view(1).unwrap()),
+            Ok(HeapMatrix::new_owned_2d([[5, 6], [3, 4]]))
+        );
+        assert_eq!(
+            stack.vstack(&heap),
+            Err(Error::DimensionMismatch {
+                expected: (2, 2),
+                found: (1, 2)
+            })
+        );
+        assert_eq!(
+            heap.hstack(&StackMatrix::new([[1, 2, 3]])),
+            Err(Error::DimensionMismatch {
+                expected: (3, 2),
+                found: (3, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn block_matrix_check() {
+        let a = StackMatrix::new([[2.0, 0.0], [0.0, 2.0]]);
+        let b = HeapMatrix::new_owned_2d([[1.0], [1.0]]);
+        let zero = StackMatrix::new([[0.0]]);
+
+        let kkt = HeapMatrix::block(&[&[&a, &b], &[&b.transposed(), &zero]]).unwrap();
+        assert_eq!(
+            kkt,
+            HeapMatrix::new_owned_2d([[2.0, 0.0, 1.0], [0.0, 2.0, 1.0], [1.0, 1.0, 0.0]])
+        );
+
+        assert_eq!(
+            HeapMatrix::block(&[&[&a, &b], &[&zero, &zero]]),
+            Err(Error::DimensionMismatch {
+                expected: (2, 1),
+                found: (1, 1)
+            })
+        );
+        assert_eq!(
+            HeapMatrix::block(&[&[&a, &b], &[&b]]),
+            Err(Error::IncorrectLength {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            HeapMatrix::<f64>::block(&[]).map(|mat| mat.get_data().len()),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn sparse_dense_round_trip_check() {
+        let dense = HeapMatrix::new_owned_2d([[0, 2, 0], [0, 0, 0], [3, 0, 4]]);
+        let sparse = SparseMatrix::from_dense(&dense);
+
+        assert_eq!(sparse.nnz(), 3);
+        assert_eq!(sparse.row_offsets(), &[0, 1, 1, 3]);
+        assert_eq!(sparse.column_indices(), &[1, 0, 2]);
+        assert_eq!(sparse.values(), &[2, 3, 4]);
+        assert_eq!(sparse.to_heap_matrix(), dense);
+        assert_eq!(sparse, dense);
+        assert_eq!(dense, sparse);
+        assert_eq!(sparse, StackMatrix::new([[0, 2, 0], [0, 0, 0], [3, 0, 4]]));
+        assert_ne!(sparse, HeapMatrix::new_owned_2d([[0, 2, 0], [0, 0, 0]]));
+
+        assert_eq!(sparse.get_at(1, 0), Ok(2));
+        assert_eq!(sparse.get_at(1, 2), Ok(0));
+        assert!(sparse.get_at(3, 0).is_err());
+        assert_eq!(sparse.to_printable(), dense.to_printable());
+    }
+
+    #[test]
+    fn sparse_from_csr_check() {
+        let sparse = SparseMatrix::from_csr(
+            3,
+            2,
+            vec![0, 3, 4],
+            vec![2, 0, 2, 1],
+            vec![1, 5, 2, 0],
+        )
+        .unwrap();
+        assert_eq!(sparse, HeapMatrix::new_owned_2d([[5, 0, 3], [0, 0, 0]]));
+        assert_eq!(sparse.nnz(), 2);
+        assert_eq!(sparse.row_offsets(), &[0, 2, 2]);
+
+        assert_eq!(
+            SparseMatrix::from_csr(3, 2, vec![0, 1], vec![0], vec![1]),
+            Err(Error::IncorrectLength {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            SparseMatrix::from_csr(3, 2, vec![0, 1, 1], vec![3], vec![1]),
+            Err(Error::OutOfRange {
+                index: (3, 0),
+                bounds: (3, 2)
+            })
+        );
+        assert_eq!(
+            SparseMatrix::from_csr(3, 2, vec![0, 2, 1], vec![0], vec![1]),
+            Err(Error::InvalidOffset {
+                index: 2,
+                offset: 1
+            })
+        );
+        assert_eq!(
+            SparseMatrix::from_csr(3, 2, vec![1, 1, 1], vec![0], vec![1]),
+            Err(Error::InvalidOffset {
+                index: 0,
+                offset: 1
+            })
+        );
+        assert_eq!(
+            SparseMatrix::from_csr(3, 2, vec![0, 1, 2], vec![0], vec![1]),
+            Err(Error::IncorrectLength {
+                expected: 1,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn sparse_products_check() {
+        let a = HeapMatrix::new_owned_2d([[1, 0, 2], [0, 0, 3], [4, 0, 0]]);
+        let b = HeapMatrix::new_owned_2d([[0, 1], [5, 0], [0, -2]]);
+        let sparse_a = SparseMatrix::from_dense(&a);
+        let sparse_b = SparseMatrix::from_dense(&b);
+
+        assert_eq!(sparse_a.mul_vec(&[1, 2, 3]), Ok(vec![7, 9, 4]));
+        assert_eq!(&sparse_a * &b, a.clone() * b.clone());
+        assert_eq!(sparse_a.mul_dense(&b), Ok(a.clone() * b.clone()));
+        assert_eq!(&sparse_a * &sparse_b, a.clone() * b.clone());
+        assert_eq!(
+            sparse_b.mul_sparse(&sparse_a),
+            Err(Error::DimensionMismatch {
+                expected: (3, 2),
+                found: (3, 3)
+            })
+        );
+
+        // Products that cancel out are not stored.
+        let cancel = SparseMatrix::from_dense(&HeapMatrix::new_owned_2d([[1, 1]]));
+        let column = SparseMatrix::from_dense(&HeapMatrix::new_owned_2d([[2], [-2]]));
+        assert_eq!((cancel * column).nnz(), 0);
+    }
+
+    #[test]
+    fn coo_assembly_check() {
+        let mut coo = CooMatrix::new(3, 2);
+        coo.push(2, 1, 4).unwrap();
+        coo.push(0, 0, 1).unwrap();
+        coo.push(2, 1, 3).unwrap();
+        coo.push(1, 0, 5).unwrap();
+        coo.push(1, 0, -5).unwrap();
+        assert_eq!(
+            coo.push(3, 0, 1),
+            Err(Error::OutOfRange {
+                index: (3, 0),
+                bounds: (3, 2)
+            })
+        );
+        assert_eq!(coo.len(), 5);
+
+        let dense = HeapMatrix::new_owned_2d([[1, 0, 0], [0, 0, 7]]);
+        let csr = coo.to_csr();
+        let csc = coo.to_csc();
+        assert_eq!(csr, dense);
+        assert_eq!(csr.nnz(), 2);
+        assert_eq!(csc, dense);
+        assert_eq!(csc.column_offsets(), &[0, 1, 1, 2]);
+        assert_eq!(csc.row_indices(), &[0, 1]);
+
+        assert_eq!(CooMatrix::from(&csr).to_csc(), csc);
+        coo.clear();
+        assert!(coo.is_empty());
+        assert_eq!(coo.to_csr(), SparseMatrix::new(3, 2));
+    }
+
+    #[test]
+    fn csc_conversions_and_slicing_check() {
+        let dense = HeapMatrix::new_owned_2d([[0, 2, 0, 1], [3, 0, 0, 0], [0, 5, 6, 0]]);
+        let csr = SparseMatrix::from_dense(&dense);
+        let csc = CscMatrix::from_dense(&dense);
+
+        assert_eq!(csr.to_csc(), csc);
+        assert_eq!(csc.to_csr(), csr);
+        assert_eq!(csc, csr);
+        assert_eq!(csc.to_heap_matrix(), dense);
+        assert_eq!(csc.get_at(2, 2), Ok(6));
+        assert_eq!(csc.get_at(0, 2), Ok(0));
+        assert_eq!(csc.column_entries(1), (&[0, 2][..], &[2, 5][..]));
+        assert_eq!(csc.mul_vec(&[1, 1, 1, 1]), csr.mul_vec(&[1, 1, 1, 1]));
+
+        let middle = csc.columns_slice(1, 2).unwrap();
+        assert_eq!(middle, HeapMatrix::new_owned_2d([[2, 0], [0, 0], [5, 6]]));
+        assert_eq!(middle.column_offsets(), &[0, 2, 3]);
+        assert!(csc.columns_slice(3, 2).is_err());
+
+        assert_eq!(
+            CscMatrix::from_csc(2, 2, vec![0, 2, 2], vec![1, 1], vec![1, 2]),
+            Ok(CscMatrix::from_dense(&HeapMatrix::new_owned_2d([
+                [0, 0],
+                [3, 0]
+            ])))
+        );
+        assert_eq!(
+            CscMatrix::from_csc(2, 2, vec![0, 1, 1], vec![2], vec![1]),
+            Err(Error::OutOfRange {
+                index: (0, 2),
+                bounds: (2, 2)
+            })
+        );
+        assert_eq!(
+            CscMatrix::from_csc(2, 2, vec![0, 2, 1], vec![0], vec![1]),
+            Err(Error::InvalidOffset {
+                index: 2,
+                offset: 1
+            })
+        );
+    }
+
+    fn tridiagonal(n: usize, lower: f64, diagonal: f64, upper: f64) -> SparseMatrix<f64> {
+        let mut coo = CooMatrix::with_capacity(n, n, 3 * n);
+        for i in 0..n {
+            coo.push(i, i, diagonal).unwrap();
+            if i > 0 {
+                coo.push(i - 1, i, lower).unwrap();
+            }
+            if i + 1 < n {
+                coo.push(i + 1, i, upper).unwrap();
+            }
+        }
+
+        coo.to_csr()
+    }
+
+    fn assert_solves(
+        a: &SparseMatrix<f64>,
+        report: &SolverReport<f64>,
+        expected: &[f64],
+    ) {
+        let residual: Vec<f64> = a.mul_vec(&report.solution).unwrap();
+        let b = a.mul_vec(expected).unwrap();
+        for ((x, e), (r, b)) in report
+            .solution
+            .iter()
+            .zip(expected)
+            .zip(residual.iter().zip(&b))
+        {
+            assert!(
+                (x - e).abs() < 1e-6,
+                "{:?} != {:?}",
+                report.solution,
+                expected
+            );
+            assert!((r - b).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn conjugate_gradient_check() {
+        let a = tridiagonal(50, -1.0, 2.0, -1.0);
+        let expected: Vec<f64> = (0..50).map(|i| (i as f64 * 0.3).sin()).collect();
+        let b = a.mul_vec(&expected).unwrap();
+        let options = SolverOptions {
+            tolerance: 1e-12,
+            ..SolverOptions::default()
+        };
+
+        let plain =
+            conjugate_gradient(&a, &b, &IdentityPreconditioner, &options).unwrap();
+        assert_solves(&a, &plain, &expected);
+        assert!(plain.iterations <= 50);
+        assert!(
+            plain.residual_norm <= 1e-12 * b.iter().map(|b| b * b).sum::<f64>().sqrt()
+        );
+
+        let jacobi = JacobiPreconditioner::new(&a).unwrap();
+        assert_solves(
+            &a,
+            &conjugate_gradient(&a, &b, &jacobi, &options).unwrap(),
+            &expected,
+        );
+
+        // ILU(0) of a tridiagonal matrix is its exact LU decomposition.
+        let ilu = Ilu0Preconditioner::new(&a).unwrap();
+        let report = conjugate_gradient(&a, &b, &ilu, &options).unwrap();
+        assert_solves(&a, &report, &expected);
+        assert_eq!(report.iterations, 1);
+
+        let dense = a.to_heap_matrix();
+        assert_solves(
+            &a,
+            &conjugate_gradient(&dense, &b, &IdentityPreconditioner, &options).unwrap(),
+            &expected,
+        );
+    }
+
+    #[test]
+    fn conjugate_gradient_incomplete_ilu_check() {
+        // The five-point Laplacian on an 8 by 8 grid, whose LU decomposition
+        // fills in the band between the diagonals, so ILU(0) is not exact.
+        let side = 8;
+        let n = side * side;
+        let mut coo = CooMatrix::with_capacity(n, n, 5 * n);
+        for i in 0..n {
+            let (x, y) = (i % side, i / side);
+            coo.push(i, i, 4.0).unwrap();
+            if x > 0 {
+                coo.push(i - 1, i, -1.0).unwrap();
+            }
+            if x + 1 < side {
+                coo.push(i + 1, i, -1.0).unwrap();
+            }
+            if y > 0 {
+                coo.push(i - side, i, -1.0).unwrap();
+            }
+            if y + 1 < side {
+                coo.push(i + side, i, -1.0).unwrap();
+            }
+        }
+        let a = coo.to_csr();
+        let expected: Vec<f64> = (0..n).map(|i| (i as f64 * 0.2).cos()).collect();
+        let b = a.mul_vec(&expected).unwrap();
+        let options = SolverOptions {
+            tolerance: 1e-12,
+            ..SolverOptions::default()
+        };
+
+        let ilu = Ilu0Preconditioner::new(&a).unwrap();
+        let error = ilu
+            .apply(&b)
+            .iter()
+            .zip(&expected)
+            .fold(0.0_f64, |max, (x, e)| max.max((x - e).abs()));
+        assert!(error > 1e-3);
+
+        let plain =
+            conjugate_gradient(&a, &b, &IdentityPreconditioner, &options).unwrap();
+        let report = conjugate_gradient(&a, &b, &ilu, &options).unwrap();
+        assert_solves(&a, &report, &expected);
+        assert!(report.iterations > 1);
+        assert!(report.iterations < plain.iterations);
+    }
+
+    #[test]
+    fn bicgstab_and_gmres_check() {
+        let a = tridiagonal(40, -1.5, 3.0, -0.5);
+        let expected: Vec<f64> = (0..40).map(|i| 1.0 + i as f64 / 10.0).collect();
+        let b = a.mul_vec(&expected).unwrap();
+        let options = SolverOptions {
+            tolerance: 1e-12,
+            restart: 10,
+            ..SolverOptions::default()
+        };
+        let jacobi = JacobiPreconditioner::new(&a).unwrap();
+        let ilu = Ilu0Preconditioner::new(&a).unwrap();
+
+        assert_solves(
+            &a,
+            &bicgstab(&a, &b, &IdentityPreconditioner, &options).unwrap(),
+            &expected,
+        );
+        assert_solves(&a, &bicgstab(&a, &b, &jacobi, &options).unwrap(), &expected);
+        assert_solves(&a, &bicgstab(&a, &b, &ilu, &options).unwrap(), &expected);
+
+        assert_solves(
+            &a,
+            &gmres(&a, &b, &IdentityPreconditioner, &options).unwrap(),
+            &expected,
+        );
+        assert_solves(&a, &gmres(&a, &b, &jacobi, &options).unwrap(), &expected);
+        assert_solves(&a, &gmres(&a, &b, &ilu, &options).unwrap(), &expected);
+
+        let csc = a.to_csc();
+        assert_solves(&a, &gmres(&csc, &b, &ilu, &options).unwrap(), &expected);
+    }
+
+    #[test]
+    fn iterative_solver_errors_check() {
+        let a = tridiagonal(10, -1.0, 2.0, -1.0);
+        let options = SolverOptions {
+            tolerance: 1e-12,
+            max_iterations: 2,
+            restart: 30,
+        };
+
+        assert_eq!(
+            conjugate_gradient(&a, &[1.0; 10], &IdentityPreconditioner, &options),
+            Err(Error::NoConvergence { iterations: 2 })
+        );
+        assert_eq!(
+            gmres(&a, &[1.0; 10], &IdentityPreconditioner, &options),
+            Err(Error::NoConvergence { iterations: 2 })
+        );
+        assert_eq!(
+            bicgstab(&a, &[1.0; 3], &IdentityPreconditioner, &options),
+            Err(Error::IncorrectLength {
+                expected: 10,
+                found: 3
+            })
+        );
+        assert_eq!(
+            gmres(
+                &SparseMatrix::<f64>::new(3, 2),
+                &[0.0; 2],
+                &IdentityPreconditioner,
+                &options
+            ),
+            Err(Error::NotSquare { dimensions: (3, 2) })
+        );
+        assert_eq!(
+            conjugate_gradient(&a, &[0.0; 10], &IdentityPreconditioner, &options)
+                .map(|report| report.iterations),
+            Ok(0)
+        );
+
+        let missing_diagonal =
+            SparseMatrix::from_dense(&HeapMatrix::new_owned_2d([[0.0, 1.0], [1.0, 0.0]]));
+        assert_eq!(
+            Ilu0Preconditioner::new(&missing_diagonal),
+            Err(Error::Singular)
+        );
+        assert_eq!(
+            JacobiPreconditioner::new(&missing_diagonal),
+            Err(Error::Singular)
+        );
+    }
+
+    #[test]
+    fn diagonal_matrix_check() {
+        let d = DiagonalMatrix::new(&[2.0, 4.0, 5.0]);
+        let dense = HeapMatrix::new_owned_2d([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        assert_eq!(
+            d,
+            HeapMatrix::new_owned_2d([[2.0, 0.0, 0.0], [0.0, 4.0, 0.0], [0.0, 0.0, 5.0]])
+        );
+        assert_eq!(d.get_at(1, 0), Ok(0.0));
+        assert_eq!(d.determinant(), 40.0);
+        assert_eq!(d.mul_vec(&[1.0, 1.0, 2.0]), Ok(vec![2.0, 4.0, 10.0]));
+        assert_eq!(
+            d.clone() * dense.clone(),
+            d.to_heap_matrix().mat_mul::<_, HeapMatrix<f64>>(&dense)
+        );
+        assert_eq!(d.solve_vec(&[2.0, 2.0, 10.0]), Ok(vec![1.0, 0.5, 2.0]));
+        assert_eq!(d.try_inverse(), Ok(DiagonalMatrix::new(&[0.5, 0.25, 0.2])));
+        assert_eq!(
+            DiagonalMatrix::new(&[1.0, 0.0]).solve_vec(&[1.0, 1.0]),
+            Err(Error::Singular)
+        );
+        assert_eq!(
+            d.clone() * DiagonalMatrix::identity(3) + DiagonalMatrix::new(&[1.0; 3]),
+            DiagonalMatrix::new(&[3.0, 5.0, 6.0])
+        );
+        assert_eq!(
+            HeapMatrix::new_owned_2d([[1.0, 1.0, 1.0]; 3]) - d.clone(),
+            HeapMatrix::new_owned_2d([
+                [-1.0, 1.0, 1.0],
+                [1.0, -3.0, 1.0],
+                [1.0, 1.0, -4.0]
+            ])
+        );
+        assert_eq!(
+            DiagonalMatrix::from_dense(&StackMatrix::new([
+                [2.0, 9.0, 9.0],
+                [9.0, 4.0, 9.0],
+                [9.0, 9.0, 5.0]
+            ])),
+            Ok(d)
+        );
+    }
+
+    #[test]
+    fn triangular_matrix_check() {
+        let upper = UpperTriangular::new(3, &[1, 2, 3, 4, 5, 6]).unwrap();
+        let dense_upper = HeapMatrix::new_owned_2d([[1, 2, 3], [0, 4, 5], [0, 0, 6]]);
+        assert_eq!(upper, dense_upper);
+        assert_eq!(UpperTriangular::from_dense(&dense_upper), Ok(upper.clone()));
+        assert_eq!(upper.determinant(), 24);
+        assert_eq!(upper.mul_vec(&[1, 1, 1]), Ok(vec![6, 9, 6]));
+
+        let lower = upper.transpose();
+        assert_eq!(
+            lower,
+            HeapMatrix::new_owned_2d([[1, 0, 0], [2, 4, 0], [3, 5, 6]])
+        );
+        assert_eq!(lower.data(), &[1, 2, 4, 3, 5, 6]);
+        assert_eq!(lower.transpose(), upper);
+        assert_eq!(
+            upper.clone() * upper.clone(),
+            dense_upper.clone() * dense_upper.clone()
+        );
+        assert_eq!(
+            lower.clone() * lower.clone(),
+            lower.to_heap_matrix() * lower.to_heap_matrix()
+        );
+        assert_eq!(
+            upper.clone() * dense_upper.clone(),
+            dense_upper.clone() * dense_upper.clone()
+        );
+        assert_eq!(
+            dense_upper.clone() * lower.clone(),
+            dense_upper.clone() * lower.to_heap_matrix()
+        );
+        assert_eq!(
+            UpperTriangular::new(2, &[1, 2]),
+            Err(Error::IncorrectLength {
+                expected: 3,
+                found: 2
+            })
+        );
+
+        let upper = UpperTriangular::new(2, &[2.0, 1.0, 4.0]).unwrap();
+        assert_eq!(upper.solve_vec(&[4.0, 8.0]), Ok(vec![1.0, 2.0]));
+        assert_eq!(upper.transpose().solve_vec(&[2.0, 9.0]), Ok(vec![1.0, 2.0]));
+        assert_eq!(
+            upper.solve(&HeapMatrix::new_owned_2d([[4.0, 2.0], [8.0, 0.0]])),
+            Ok(HeapMatrix::new_owned_2d([[1.0, 1.0], [2.0, 0.0]]))
+        );
+        assert_eq!(
+            LowerTriangular::new(2, &[1.0, 1.0, 0.0])
+                .unwrap()
+                .solve_vec(&[1.0, 1.0]),
+            Err(Error::Singular)
+        );
+    }
+
+    #[test]
+    fn symmetric_matrix_check() {
+        let sym = SymmetricMatrix::new(3, &[4.0, 1.0, 3.0, 0.0, 2.0, 5.0]).unwrap();
+        let dense =
+            HeapMatrix::new_owned_2d([[4.0, 1.0, 0.0], [1.0, 3.0, 2.0], [0.0, 2.0, 5.0]]);
+
+        assert_eq!(sym, dense);
+        assert_eq!(SymmetricMatrix::from_dense(&dense), Ok(sym.clone()));
+        assert_eq!(sym.get_at(2, 1), sym.get_at(1, 2));
+        assert_eq!(sym.mul_vec(&[1.0, 2.0, 3.0]), Ok(vec![6.0, 13.0, 19.0]));
+        assert_eq!(sym.clone() * sym.clone(), dense.clone() * dense.clone());
+        assert_eq!(sym.clone() + dense.clone(), dense.clone() + dense.clone());
+        assert_eq!(
+            sym.clone() - sym.clone(),
+            SymmetricMatrix::new(3, &[0.0; 6]).unwrap()
+        );
+
+        let x = sym.solve_vec(&[6.0, 13.0, 19.0]).unwrap();
+        assert_approx_eq(
+            &HeapMatrix::new(&x, 1, 3),
+            &HeapMatrix::new(&[1.0, 2.0, 3.0], 1, 3),
+        );
+        assert_eq!(
+            sym.to_heap_matrix().cholesky().map(|c| c.l().clone()),
+            CholeskyDecomposition::new(&sym).map(|c| c.l().clone())
+        );
+    }
+
+    #[test]
+    fn banded_matrix_check() {
+        let tri = BandedMatrix::tridiagonal(&[-1.0; 3], &[4.0; 4], &[-2.0; 3]).unwrap();
+        let dense = HeapMatrix::new_owned_2d([
+            [4.0, -2.0, 0.0, 0.0],
+            [-1.0, 4.0, -2.0, 0.0],
+            [0.0, -1.0, 4.0, -2.0],
+            [0.0, 0.0, -1.0, 4.0],
+        ]);
+
+        assert_eq!(tri, dense);
+        assert_eq!(BandedMatrix::from_dense(&dense, 1, 1), Ok(tri.clone()));
+        assert_eq!((tri.lower_bandwidth(), tri.upper_bandwidth()), (1, 1));
+        assert_eq!(BandedMatrix::from_dense(&dense, 2, 3), Ok(tri.clone()));
+        assert_ne!(BandedMatrix::from_dense(&dense, 0, 1), Ok(tri.clone()));
+        assert_eq!(
+            tri.clone() + BandedMatrix::from_dense(&dense, 2, 0).unwrap(),
+            BandedMatrix::tridiagonal(&[-2.0; 3], &[8.0; 4], &[-2.0; 3]).unwrap()
+        );
+        assert_eq!(tri.mul_vec(&[1.0; 4]), Ok(vec![2.0, 1.0, 1.0, 3.0]));
+        assert_eq!(tri.clone() * tri.clone(), dense.clone() * dense.clone());
+        assert_eq!((tri.clone() * tri.clone()).lower_bandwidth(), 2);
+        assert_eq!(tri.clone() + dense.clone(), dense.clone() + dense.clone());
+        assert_eq!(
+            tri.clone() - BandedMatrix::from_dense(&dense, 0, 0).unwrap(),
+            HeapMatrix::new_owned_2d([
+                [0.0, -2.0, 0.0, 0.0],
+                [-1.0, 0.0, -2.0, 0.0],
+                [0.0, -1.0, 0.0, -2.0],
+                [0.0, 0.0, -1.0, 0.0],
+            ])
+        );
+
+        let x = tri.solve_vec(&[2.0, 1.0, 1.0, 3.0]).unwrap();
+        assert_approx_eq(
+            &HeapMatrix::new(&x, 1, 4),
+            &HeapMatrix::new(&[1.0; 4], 1, 4),
+        );
+        assert_approx_eq(
+            &tri.solve(&dense).unwrap(),
+            &DiagonalMatrix::identity(4).to_heap_matrix(),
+        );
+
+        let wide =
+            BandedMatrix::new(3, 1, 2, &[9, 1, 2, 3, 4, 5, 6, 9, 7, 8, 9, 9]).unwrap();
+        assert_eq!(
+            wide,
+            HeapMatrix::new_owned_2d([[1, 2, 3], [4, 5, 6], [0, 7, 8]])
+        );
+        assert_eq!(
+            BandedMatrix::tridiagonal(&[1.0], &[1.0; 3], &[1.0; 2]),
+            Err(Error::IncorrectLength {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            BandedMatrix::tridiagonal(&[1.0], &[0.0, 1.0], &[1.0])
+                .unwrap()
+                .solve_vec(&[1.0, 1.0]),
+            Err(Error::Singular)
+        );
+
+        let spd = BandedMatrix::tridiagonal(&[-1.0; 3], &[2.0; 4], &[-1.0; 3]).unwrap();
+        let report = conjugate_gradient(
+            &spd,
+            &[1.0, 0.0, 0.0, 1.0],
+            &IdentityPreconditioner,
+            &SolverOptions::default(),
+        )
+        .unwrap();
+        assert_approx_eq(
+            &HeapMatrix::new(&report.solution, 1, 4),
+            &HeapMatrix::new(&[1.0; 4], 1, 4),
+        );
+    }
+
+    #[test]
+    fn column_major_layout_check() {
+        let row_major = HeapMatrix::new_owned_2d([[1.0, 2.0, 3.0], [4.0, 5.0, 7.0]]);
+        let column_major =
+            HeapMatrix::new_column_major(&[1.0, 4.0, 2.0, 5.0, 3.0, 7.0], 3, 2);
+
+        assert_eq!(column_major.layout(), Layout::ColumnMajor);
+        assert_eq!(column_major.get_strides(), (2, 1));
+        assert!(!column_major.is_contiguous());
+        assert_eq!(column_major, row_major);
+        assert_eq!(row_major, column_major);
+        assert_eq!(column_major.get_at(2, 1), Ok(7.0));
+        assert_eq!(column_major.to_vec(), row_major.to_vec());
+        assert_eq!(column_major.get_data(), &[1.0, 4.0, 2.0, 5.0, 3.0, 7.0]);
+        assert_eq!(column_major.to_printable(), row_major.to_printable());
+        assert_eq!(
+            column_major.column(1).copied().collect::<Vec<_>>(),
+            vec![2.0, 5.0]
+        );
+        assert_eq!(
+            column_major.view(1, 0, 2, 2).unwrap(),
+            row_major.view(1, 0, 2, 2).unwrap()
+        );
+
+        let converted = row_major.to_layout(Layout::ColumnMajor);
+        assert_eq!(converted.get_data(), column_major.get_data());
+        assert_eq!(
+            converted.to_layout(Layout::RowMajor).get_data(),
+            row_major.get_data()
+        );
+
+        assert_eq!(
+            column_major.clone() * row_major.transpose(),
+            row_major.clone() * row_major.transpose()
+        );
+        assert_eq!(column_major.clone() * 2.0, row_major.clone() * 2.0);
+        assert_eq!(column_major.transpose(), row_major.transpose());
+        assert_eq!(column_major.transpose().layout(), Layout::ColumnMajor);
+        assert_eq!((column_major.clone() * 2.0).layout(), Layout::ColumnMajor);
+        assert_eq!(
+            column_major.hstack(&row_major).unwrap().layout(),
+            Layout::RowMajor
+        );
+        assert_eq!(
+            (column_major.clone() - row_major.clone()).layout(),
+            Layout::RowMajor
+        );
+
+        let square = HeapMatrix::new_column_major(&[2.0, 1.0, 1.0, 3.0], 2, 2);
+        assert_eq!(square, HeapMatrix::new_owned_2d([[2.0, 1.0], [1.0, 3.0]]));
+        assert_eq!(square.determinant(), Ok(5.0));
+        assert_approx_eq(
+            &square.try_inverse().unwrap(),
+            &HeapMatrix::new_owned_2d([[0.6, -0.2], [-0.2, 0.4]]),
+        );
+        let report = conjugate_gradient(
+            &square,
+            &[3.0, 4.0],
+            &IdentityPreconditioner,
+            &SolverOptions::default(),
+        )
+        .unwrap();
+        assert_approx_eq(
+            &HeapMatrix::new(&report.solution, 1, 2),
+            &HeapMatrix::new(&[1.0, 1.0], 1, 2),
+        );
+    }
+
+    #[test]
+    fn column_major_resizing_keeps_layout_check() {
+        let mut mat = HeapMatrix::new_column_major(&[1, 3, 2, 4], 2, 2);
+
+        mat.insert_row_at(1, [5, 6]).unwrap();
+        mat.insert_column(0, [7, 8, 9]).unwrap();
+        assert_eq!(
+            mat,
+            HeapMatrix::new_owned_2d([[7, 1, 2], [8, 5, 6], [9, 3, 4]])
+        );
+        assert_eq!(mat.layout(), Layout::ColumnMajor);
+        assert_eq!(mat.get_data(), &[7, 8, 9, 1, 5, 3, 2, 6, 4]);
+
+        assert_eq!(mat.remove_column(1), Ok(vec![1, 5, 3]));
+        assert_eq!(mat.remove_row(0), Ok(vec![7, 2]));
+        mat.swap_columns(0, 1).unwrap();
+        assert_eq!(mat, HeapMatrix::new_owned_2d([[6, 8], [4, 9]]));
+        assert_eq!(mat.insert_row_at(5, [1, 2]).map_err(|_| ()), Err(()));
+        assert_eq!(mat.layout(), Layout::ColumnMajor);
+
+        mat.resize(3, 1, 0);
+        assert_eq!(mat, HeapMatrix::new_owned_2d([[6, 8, 0]]));
+        mat.set_layout(Layout::RowMajor);
+        assert_eq!(mat.get_data(), &[6, 8, 0]);
+
+        let mut rectangular = HeapMatrix::new_column_major(&[1, 4, 2, 5, 3, 6], 3, 2);
+        rectangular.transpose_mut();
+        assert_eq!(
+            rectangular,
+            HeapMatrix::new_owned_2d([[1, 4], [2, 5], [3, 6]])
+        );
+    }
+
+    #[test]
+    fn column_major_lanes_in_place_check() {
+        let mut mat = HeapMatrix::new_column_major(&[1, 4, 7, 2, 5, 8, 3, 6, 9], 3, 3);
+
+        mat.swap_rows(0, 2).unwrap();
+        assert_eq!(mat.get_data(), &[7, 4, 1, 8, 5, 2, 9, 6, 3]);
+        mat.swap_columns(0, 1).unwrap();
+        assert_eq!(mat.get_data(), &[8, 5, 2, 7, 4, 1, 9, 6, 3]);
+        mat.swap_rows(1, 1).unwrap();
+        assert!(mat.swap_rows(0, 3).is_err());
+
+        assert_eq!(mat.remove_row(1), Ok(vec![5, 4, 6]));
+        assert_eq!(mat.get_data(), &[8, 2, 7, 1, 9, 3]);
+        mat.resize(4, 2, 0);
+        assert_eq!(mat.get_data(), &[8, 2, 7, 1, 9, 3, 0, 0]);
+        mat.resize(2, 3, 0);
+        assert_eq!(mat.get_data(), &[8, 2, 0, 7, 1, 0]);
+        assert_eq!(mat.layout(), Layout::ColumnMajor);
+
+        let mut empty = HeapMatrix::new_column_major(&[], 0, 0);
+        empty.insert_row_at(0, [1, 2]).unwrap();
+        empty.insert_row_at(0, [3, 4]).unwrap();
+        assert_eq!(empty, HeapMatrix::new_owned_2d([[3, 4], [1, 2]]));
+        assert_eq!(empty.get_data(), &[3, 1, 4, 2]);
+    }
 }
@@ -1,47 +1,49 @@
-use crate::{common::Numeric, error::*};
-use std::ops::*;
+use crate::{
+    common::{Field, Numeric, Real, Ring, Semiring, SignMagnitude, Zero},
+    decompositions::{
+        CholeskyDecomposition, Eigen, LuDecomposition, QrDecomposition,
+        SingularValueDecomposition, SymmetricEigen,
+    },
+    error::*,
+    implementations::{HeapMatrix, MatrixView, MatrixViewMut},
+    iterators::{Lanes, LanesMut, StridedIter, StridedIterMut},
+    numbers::Complex,
+};
+use std::{fmt::Display, ops::*};
 
 pub trait MatrixRef<'a, T: Numeric> {
-    /// Gets the Matrix's inner data as a &[T]
-    fn get_data(&self) -> &[T];
     /// Gets the Matrix's x length
     fn get_x_len(&self) -> usize;
     /// Gets the Matrix's y length
     fn get_y_len(&self) -> usize;
-
-    /// Gets the first item of the inner matrix data as a reference
-    fn first(&self) -> Option<&T> { self.get_data().first() }
-    /// Gets the last item of the inner matrix data as a reference
-    fn last(&self) -> Option<&T> { self.get_data().last() }
-
     /// Takes x and y coordinates and returns a T if the coordinates are within
     /// the bounds of the Matrix, panics otherwise.
-    fn get_at_unchecked(&self, x: usize, y: usize) -> T {
-        assert!(x * y <= self.get_x_len() * self.get_y_len());
-        *self
-            .get_data()
-            .get(y * self.get_x_len() + x)
-            .unwrap_or_else(|| panic!("Index ({}, {}) is out of range", x, y))
-    }
+    fn get_at_unchecked(&self, x: usize, y: usize) -> T;
 
     /// Takes x and y coordinates and returns a Result<T>.
     fn get_at(&self, x: usize, y: usize) -> Result<T> {
-        if let Some(coord_data) = self.get_data().get(y * self.get_x_len() + x) {
-            Ok(*coord_data)
-        } else {
-            Err(Error::OutOfRange)
+        if x >= self.get_x_len() || y >= self.get_y_len() {
+            return Err(Error::OutOfRange {
+                index: (x, y),
+                bounds: (self.get_x_len(), self.get_y_len()),
+            });
         }
+
+        Ok(self.get_at_unchecked(x, y))
     }
 
-    /// Takes a y index to get a reference to the corresponding "row" of the
-    /// inner matrix data.
-    fn get_row_at(&self, row_index: usize) -> &[T] {
-        &self.get_data()[row_index % self.get_x_len()
-            ..(row_index % self.get_x_len()) + self.get_x_len()]
+    /// Copies the Matrix's elements into a Vec<T> in row-major order.
+    fn to_vec(&self) -> Vec<T> {
+        (0..self.get_y_len())
+            .flat_map(|y| (0..self.get_x_len()).map(move |x| self.get_at_unchecked(x, y)))
+            .collect()
     }
 
     /// Formats the Matrix in a way that is easily printable.
-    fn to_printable(&self) -> String {
+    fn to_printable(&self) -> String
+    where
+        T: Display,
+    {
         let mut out = String::new();
         for y in 0..self.get_y_len() {
             for x in 0..self.get_x_len() {
@@ -54,6 +56,156 @@ pub trait MatrixRef<'a, T: Numeric> {
     }
 }
 
+/// A Matrix whose elements are stored densely in a slice, where the element at
+/// (x, y) is found at `x * x_stride + y * y_stride`. Dense Matrices can be
+/// borrowed as views and iterated over by row or column without copying.
+pub trait DenseMatrixRef<'a, T: Numeric>: MatrixRef<'a, T> {
+    /// Gets the Matrix's inner data as a &[T]. The first element is the one at
+    /// (0, 0), and the rest are found using the Matrix's strides.
+    fn get_data(&self) -> &[T];
+
+    /// Gets the distance in the inner data between horizontally adjacent
+    /// elements and between vertically adjacent elements, as an (x, y) pair.
+    /// Defaults to the strides of a row-major Matrix.
+    fn get_strides(&self) -> (usize, usize) { (1, self.get_x_len()) }
+
+    /// Returns whether the inner data holds exactly the Matrix's elements in
+    /// row-major order.
+    fn is_contiguous(&self) -> bool {
+        self.get_strides() == (1, self.get_x_len())
+            && self.get_data().len() == self.get_x_len() * self.get_y_len()
+    }
+
+    /// Gets the first item of the inner matrix data as a reference
+    fn first(&self) -> Option<&T> { self.get_data().first() }
+    /// Gets the last item of the inner matrix data as a reference
+    fn last(&self) -> Option<&T> {
+        let (x_len, y_len) = (self.get_x_len(), self.get_y_len());
+        if x_len == 0 || y_len == 0 {
+            return None;
+        }
+
+        let (x_stride, y_stride) = self.get_strides();
+        self.get_data()
+            .get((x_len - 1) * x_stride + (y_len - 1) * y_stride)
+    }
+
+    /// Takes a y index to get a reference to the corresponding "row" of the
+    /// inner matrix data. Panics if the row is out of range, or if the
+    /// elements of a row are not adjacent in the inner data, such as in a
+    /// transposed view.
+    fn get_row_at(&self, row_index: usize) -> &[T] {
+        let start = row_start(self, row_index);
+
+        &self.get_data()[start..start + self.get_x_len()]
+    }
+
+    /// Returns an iterator over the elements of the row at the given y index,
+    /// panicking if there is no such row.
+    fn row(&self, y: usize) -> StridedIter<'_, T> {
+        assert!(y < self.get_y_len(), "Row {} is out of range", y);
+        let (x_stride, y_stride) = self.get_strides();
+
+        StridedIter::new(self.get_data(), y * y_stride, x_stride, self.get_x_len())
+    }
+
+    /// Returns an iterator over the elements of the column at the given x
+    /// index, panicking if there is no such column.
+    fn column(&self, x: usize) -> StridedIter<'_, T> {
+        assert!(x < self.get_x_len(), "Column {} is out of range", x);
+        let (x_stride, y_stride) = self.get_strides();
+
+        StridedIter::new(self.get_data(), x * x_stride, y_stride, self.get_y_len())
+    }
+
+    /// Returns an iterator over the rows of the Matrix, from top to bottom.
+    fn rows(&self) -> Lanes<'_, T> {
+        let (x_stride, y_stride) = self.get_strides();
+
+        Lanes::new(
+            self.get_data(),
+            self.get_y_len(),
+            y_stride,
+            self.get_x_len(),
+            x_stride,
+        )
+    }
+
+    /// Returns an iterator over the columns of the Matrix, from left to right.
+    fn columns(&self) -> Lanes<'_, T> {
+        let (x_stride, y_stride) = self.get_strides();
+
+        Lanes::new(
+            self.get_data(),
+            self.get_x_len(),
+            x_stride,
+            self.get_y_len(),
+            y_stride,
+        )
+    }
+
+    /// Returns a borrowed view of the `columns x rows` block of the Matrix
+    /// whose top-left element is at (x, y). Returns `Error::OutOfRange`, with
+    /// the exclusive end of the block as the index, if the block does not fit
+    /// in the Matrix.
+    fn view(
+        &self,
+        x: usize,
+        y: usize,
+        columns: usize,
+        rows: usize,
+    ) -> Result<MatrixView<'_, T>> {
+        let (start, end) = view_range(self, x, y, columns, rows)?;
+        let (x_stride, y_stride) = self.get_strides();
+
+        Ok(MatrixView::new(
+            &self.get_data()[start..end],
+            columns,
+            rows,
+            x_stride,
+            y_stride,
+        ))
+    }
+
+    /// Returns a borrowed view of the column at the given x index, or
+    /// `Error::OutOfRange` if there is no such column.
+    fn column_view(&self, x: usize) -> Result<MatrixView<'_, T>> {
+        self.view(x, 0, 1, self.get_y_len())
+    }
+
+    /// Returns a borrowed view of the row at the given y index, or
+    /// `Error::OutOfRange` if there is no such row.
+    fn row_view(&self, y: usize) -> Result<MatrixView<'_, T>> {
+        self.view(0, y, self.get_x_len(), 1)
+    }
+
+    /// Returns a borrowed view of the main diagonal of the Matrix as a single
+    /// column.
+    fn diagonal_view(&self) -> MatrixView<'_, T> {
+        let n = self.get_x_len().min(self.get_y_len());
+        let (start, end) = view_range(self, 0, 0, n, n)
+            .expect("the diagonal is always within the bounds of the matrix");
+        let (x_stride, y_stride) = self.get_strides();
+
+        MatrixView::new(&self.get_data()[start..end], 1, n, 0, x_stride + y_stride)
+    }
+
+    /// Returns a borrowed view of the transpose of the Matrix, which has the x
+    /// and y lengths of the Matrix swapped. No data is copied: the view reads
+    /// the Matrix with its strides swapped.
+    fn transposed(&self) -> MatrixView<'_, T> {
+        let (x_stride, y_stride) = self.get_strides();
+
+        MatrixView::new(
+            self.get_data(),
+            self.get_y_len(),
+            self.get_x_len(),
+            y_stride,
+            x_stride,
+        )
+    }
+}
+
 pub trait MatrixAlloc<'a, T: Numeric>: Matrix<'a, T> {
     /// Creates a new MatrixOpCapable<T> from the given 2d slice of data.
     fn mat_new(data: &[&[T]]) -> Self;
@@ -61,122 +213,1074 @@ pub trait MatrixAlloc<'a, T: Numeric>: Matrix<'a, T> {
     fn mat_new_1d(data: &[T], columns: usize, rows: usize) -> Self;
     /// Creates a new MatrixOpCapable<T> from the given 2d Vec<T>.
     fn mat_new_vec(data: Vec<Vec<T>>) -> Self;
+    /// Gets the `(x_len, y_len)` every Matrix of this type must have, or
+    /// `None` if the dimensions are chosen at runtime.
+    fn fixed_dimensions() -> Option<(usize, usize)> { None }
 }
 
-pub trait Matrix<'a, T: Numeric>: MatrixRef<'a, T> // + MatrixRefMut<'a, T>
-{
+pub trait Matrix<'a, T: Numeric>: DenseMatrixRef<'a, T> {
     /// Gets the Matrix's inner data as a &mut [T]
     fn get_data_mut(&mut self) -> &mut [T];
     /// Gets the first item of the inner matrix data as a mutable reference
     fn first_mut(&mut self) -> Option<&mut T> { self.get_data_mut().first_mut() }
     /// Gets the last item of the inner matrix data as a mutable reference
-    fn last_mut(&mut self) -> Option<&mut T> { self.get_data_mut().last_mut() }
+    fn last_mut(&mut self) -> Option<&mut T> {
+        let (x_len, y_len) = (self.get_x_len(), self.get_y_len());
+        if x_len == 0 || y_len == 0 {
+            return None;
+        }
+
+        let (x_stride, y_stride) = self.get_strides();
+        self.get_data_mut()
+            .get_mut((x_len - 1) * x_stride + (y_len - 1) * y_stride)
+    }
+
+    /// Takes x and y coordinates and returns a mutable reference to the
+    /// element at them, or `Error::OutOfRange` if they are out of bounds.
+    fn get_at_mut(&mut self, x: usize, y: usize) -> Result<&mut T> {
+        if x >= self.get_x_len() || y >= self.get_y_len() {
+            return Err(Error::OutOfRange {
+                index: (x, y),
+                bounds: (self.get_x_len(), self.get_y_len()),
+            });
+        }
+
+        let (x_stride, y_stride) = self.get_strides();
+        Ok(&mut self.get_data_mut()[x * x_stride + y * y_stride])
+    }
 
     /// Takes a y index to get a mutable reference to the corresponding "row" of
-    /// the inner matrix data
+    /// the inner matrix data. Panics under the same conditions as
+    /// [`MatrixRef::get_row_at`].
     fn get_row_at_mut(&mut self, row_index: usize) -> &mut [T] {
-        let x_length = self.get_x_len();
+        let start = row_start(self, row_index);
+        let x_len = self.get_x_len();
+
+        &mut self.get_data_mut()[start..start + x_len]
+    }
+
+    /// Returns an iterator over mutable references to the elements of the row
+    /// at the given y index, panicking if there is no such row.
+    fn row_mut(&mut self, y: usize) -> StridedIterMut<'_, T> {
+        assert!(y < self.get_y_len(), "Row {} is out of range", y);
+        let (x_stride, y_stride) = self.get_strides();
+        let x_len = self.get_x_len();
+
+        StridedIterMut::new(self.get_data_mut(), y * y_stride, x_stride, x_len)
+    }
+
+    /// Returns an iterator over mutable references to the elements of the
+    /// column at the given x index, panicking if there is no such column.
+    fn column_mut(&mut self, x: usize) -> StridedIterMut<'_, T> {
+        assert!(x < self.get_x_len(), "Column {} is out of range", x);
+        let (x_stride, y_stride) = self.get_strides();
+        let y_len = self.get_y_len();
+
+        StridedIterMut::new(self.get_data_mut(), x * x_stride, y_stride, y_len)
+    }
+
+    /// Returns an iterator over the rows of the Matrix which yields mutable
+    /// references to their elements.
+    fn rows_mut(&mut self) -> LanesMut<'_, T> {
+        let (x_stride, y_stride) = self.get_strides();
+        let (x_len, y_len) = (self.get_x_len(), self.get_y_len());
+
+        LanesMut::new(self.get_data_mut(), y_len, y_stride, x_len, x_stride)
+    }
+
+    /// Returns an iterator over the columns of the Matrix which yields mutable
+    /// references to their elements.
+    fn columns_mut(&mut self) -> LanesMut<'_, T> {
+        let (x_stride, y_stride) = self.get_strides();
+        let (x_len, y_len) = (self.get_x_len(), self.get_y_len());
+
+        LanesMut::new(self.get_data_mut(), x_len, x_stride, y_len, y_stride)
+    }
+
+    /// Copies every element of `src` into the Matrix, returning
+    /// `Error::DimensionMismatch` if the two have different dimensions.
+    fn copy_from<'b>(&mut self, src: &impl MatrixRef<'b, T>) -> Result<()> {
+        check_same_dimensions(self, src)?;
+
+        let (x_stride, y_stride) = self.get_strides();
+        for y in 0..self.get_y_len() {
+            for x in 0..self.get_x_len() {
+                self.get_data_mut()[x * x_stride + y * y_stride] =
+                    src.get_at_unchecked(x, y);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns a mutably borrowed view of the `columns x rows` block of the
+    /// Matrix whose top-left element is at (x, y). Returns
+    /// `Error::OutOfRange` under the same conditions as [`MatrixRef::view`].
+    fn view_mut(
+        &mut self,
+        x: usize,
+        y: usize,
+        columns: usize,
+        rows: usize,
+    ) -> Result<MatrixViewMut<'_, T>> {
+        let (start, end) = view_range(self, x, y, columns, rows)?;
+        let (x_stride, y_stride) = self.get_strides();
+
+        Ok(MatrixViewMut::new(
+            &mut self.get_data_mut()[start..end],
+            columns,
+            rows,
+            x_stride,
+            y_stride,
+        ))
+    }
+
+    /// Returns a mutably borrowed view of the column at the given x index, or
+    /// `Error::OutOfRange` if there is no such column.
+    fn column_view_mut(&mut self, x: usize) -> Result<MatrixViewMut<'_, T>> {
+        let rows = self.get_y_len();
+        self.view_mut(x, 0, 1, rows)
+    }
+
+    /// Returns a mutably borrowed view of the row at the given y index, or
+    /// `Error::OutOfRange` if there is no such row.
+    fn row_view_mut(&mut self, y: usize) -> Result<MatrixViewMut<'_, T>> {
+        let columns = self.get_x_len();
+        self.view_mut(0, y, columns, 1)
+    }
+
+    /// Returns a mutably borrowed view of the transpose of the Matrix, through
+    /// which writing the element at (x, y) writes the element at (y, x) of the
+    /// Matrix.
+    fn transposed_mut(&mut self) -> MatrixViewMut<'_, T> {
+        let (x_stride, y_stride) = self.get_strides();
+        let (x_len, y_len) = (self.get_x_len(), self.get_y_len());
 
-        &mut self.get_data_mut()[row_index % x_length..(row_index % x_length) + x_length]
+        MatrixViewMut::new(self.get_data_mut(), y_len, x_len, y_stride, x_stride)
+    }
+
+    /// Returns a mutably borrowed view of the main diagonal of the Matrix as a
+    /// single column.
+    fn diagonal_view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        let n = self.get_x_len().min(self.get_y_len());
+        let (start, end) = view_range(self, 0, 0, n, n)
+            .expect("the diagonal is always within the bounds of the matrix");
+        let (x_stride, y_stride) = self.get_strides();
+
+        MatrixViewMut::new(
+            &mut self.get_data_mut()[start..end],
+            1,
+            n,
+            0,
+            x_stride + y_stride,
+        )
     }
 }
 
-pub trait MatrixScalarOp<'a, T: Numeric>:
+pub trait MatrixScalarOp<'a, T: Ring>:
     Matrix<'a, T> + MatrixAlloc<'a, T> + Sized + Add<T> + Sub<T> + Mul<T>
 {
     fn scalar_add(&self, num: T) -> Self {
-        let data: Vec<T> = self.get_data().iter().map(|x| *x + num).collect();
+        let data: Vec<T> = self.to_vec().into_iter().map(|x| x + num).collect();
 
         Self::mat_new_1d(&data, self.get_x_len(), self.get_y_len())
     }
 
     fn scalar_sub(&self, num: T) -> Self {
-        let data: Vec<T> = self.get_data().iter().map(|x| *x - num).collect();
+        let data: Vec<T> = self.to_vec().into_iter().map(|x| x - num).collect();
 
         Self::mat_new_1d(&data, self.get_x_len(), self.get_y_len())
     }
 
     fn scalar_mul(&self, num: T) -> Self {
-        let data: Vec<T> = self.get_data().iter().map(|x| *x * num).collect();
+        let data: Vec<T> = self.to_vec().into_iter().map(|x| x * num).collect();
 
         Self::mat_new_1d(&data, self.get_x_len(), self.get_y_len())
     }
 }
 
-pub trait MatrixOp<'a, T: Numeric>:
-    Matrix<'a, T> + MatrixAlloc<'a, T> + Sized + Add + Sub + Mul + PartialEq
+pub trait MatrixOp<'a, T: Semiring>:
+    Matrix<'a, T> + MatrixAlloc<'a, T> + Sized + Add + PartialEq
 {
-    fn mat_add<Other: Matrix<'a, T>>(&self, rhs: &Other) -> Self {
+    fn mat_add<Other: MatrixRef<'a, T>>(&self, rhs: &Other) -> Self {
         assert!(
-            self.get_x_len() == rhs.get_x_len() || self.get_y_len() == rhs.get_y_len()
+            self.get_x_len() == rhs.get_x_len() && self.get_y_len() == rhs.get_y_len()
         );
 
         let data: Vec<T> = self
-            .get_data()
-            .iter()
-            .enumerate()
-            .map(|(index, x)| *x + rhs.get_data()[index])
+            .to_vec()
+            .into_iter()
+            .zip(rhs.to_vec())
+            .map(|(x, y)| x + y)
             .collect();
 
         Self::mat_new_1d(&data, self.get_x_len(), self.get_y_len())
     }
 
-    fn mat_sub<Other: Matrix<'a, T>>(&self, rhs: &Other) -> Self {
+    fn mat_sub<Other: MatrixRef<'a, T>>(&self, rhs: &Other) -> Self
+    where
+        T: Ring,
+    {
         assert!(
-            self.get_x_len() == rhs.get_x_len() || self.get_y_len() == rhs.get_y_len()
+            self.get_x_len() == rhs.get_x_len() && self.get_y_len() == rhs.get_y_len()
         );
 
         let data: Vec<T> = self
-            .get_data()
-            .iter()
-            .enumerate()
-            .map(|(index, x)| *x - rhs.get_data()[index])
+            .to_vec()
+            .into_iter()
+            .zip(rhs.to_vec())
+            .map(|(x, y)| x - y)
             .collect();
 
         Self::mat_new_1d(&data, self.get_x_len(), self.get_y_len())
     }
 
-    fn mat_mul<Other: Matrix<'a, T>, Res: MatrixOp<'a, T>>(&self, rhs: &Other) -> Res {
-        assert!(self.get_x_len() * self.get_y_len() == rhs.get_x_len() * rhs.get_y_len());
+    /// Multiplies `self` by `rhs` using the row-by-column matrix product. The
+    /// x length of `self` must be equal to the y length of `rhs`, and the
+    /// resulting matrix has the x length of `rhs` and the y length of `self`.
+    fn mat_mul<Other: MatrixRef<'a, T>, Res: MatrixOp<'a, T>>(&self, rhs: &Other) -> Res {
+        assert!(self.get_x_len() == rhs.get_y_len());
 
-        let mut data = Vec::new();
-        data.resize(self.get_x_len() * self.get_y_len(), T::default());
+        let columns = rhs.get_x_len();
+        let rows = self.get_y_len();
+        let mut data = Vec::with_capacity(columns * rows);
 
-        data.iter_mut()
-            .enumerate()
-            .map(|(index, x)| *x = self.get_data()[index] * rhs.get_data()[index])
-            .last();
+        for y in 0..rows {
+            for x in 0..columns {
+                let mut cell = T::zero();
+                for i in 0..self.get_x_len() {
+                    cell += self.get_at_unchecked(i, y) * rhs.get_at_unchecked(x, i);
+                }
+                data.push(cell);
+            }
+        }
 
-        Res::mat_new_1d(&data, self.get_x_len(), self.get_y_len())
+        Res::mat_new_1d(&data, columns, rows)
     }
 
-    fn dot_prod<Other: Matrix<'a, T>>(&self, rhs: &Other) -> Self {
-        assert!(self.get_x_len() == rhs.get_y_len());
+    /// Multiplies `self` by `rhs` element-by-element (the Hadamard product).
+    /// Both matrices must have the same x and y lengths.
+    fn hadamard_prod<Other: MatrixRef<'a, T>>(&self, rhs: &Other) -> Self {
+        assert!(
+            self.get_x_len() == rhs.get_x_len() && self.get_y_len() == rhs.get_y_len()
+        );
+
+        let data: Vec<T> = self
+            .to_vec()
+            .into_iter()
+            .zip(rhs.to_vec())
+            .map(|(x, y)| x * y)
+            .collect();
+
+        Self::mat_new_1d(&data, self.get_x_len(), self.get_y_len())
+    }
+
+    /// Computes the matrix product of `self` and `rhs`, see
+    /// [`MatrixOp::mat_mul`]. The resulting matrix type is the output of the
+    /// corresponding `Mul` implementation, so products between two
+    /// `StackMatrix`s have their dimensions checked at compile time.
+    fn dot_prod<Other: MatrixRef<'a, T>>(
+        &self,
+        rhs: &Other,
+    ) -> <Self as Mul<Other>>::Output
+    where
+        Self: Mul<Other>,
+        <Self as Mul<Other>>::Output: MatrixOp<'a, T>,
+    {
+        self.mat_mul(rhs)
+    }
+
+    /// Adds `rhs` to `self`, returning an `Error::DimensionMismatch` instead
+    /// of panicking if the dimensions of the two matrices differ.
+    fn checked_add<Other: MatrixRef<'a, T>>(&self, rhs: &Other) -> Result<Self> {
+        check_same_dimensions(self, rhs)?;
+
+        Ok(self.mat_add(rhs))
+    }
+
+    /// Subtracts `rhs` from `self`, returning an `Error::DimensionMismatch`
+    /// instead of panicking if the dimensions of the two matrices differ.
+    fn checked_sub<Other: MatrixRef<'a, T>>(&self, rhs: &Other) -> Result<Self>
+    where
+        T: Ring,
+    {
+        check_same_dimensions(self, rhs)?;
+
+        Ok(self.mat_sub(rhs))
+    }
+
+    /// Computes the matrix product of `self` and `rhs`, returning an
+    /// `Error::DimensionMismatch` instead of panicking if the x length of
+    /// `self` is not equal to the y length of `rhs`, or if `Res` has fixed
+    /// dimensions which differ from those of the product.
+    fn checked_mul<Other: MatrixRef<'a, T>, Res: MatrixOp<'a, T>>(
+        &self,
+        rhs: &Other,
+    ) -> Result<Res> {
+        check_product_dimensions(self, rhs)?;
+
+        let product = (rhs.get_x_len(), self.get_y_len());
+        if let Some(dimensions) = Res::fixed_dimensions() {
+            if dimensions != product {
+                return Err(Error::DimensionMismatch {
+                    expected: dimensions,
+                    found: product,
+                });
+            }
+        }
+
+        Ok(self.mat_mul(rhs))
+    }
+
+    /// Computes the Hadamard product of `self` and `rhs`, returning an
+    /// `Error::DimensionMismatch` instead of panicking if the dimensions of the
+    /// two matrices differ.
+    fn checked_hadamard<Other: MatrixRef<'a, T>>(&self, rhs: &Other) -> Result<Self> {
+        check_same_dimensions(self, rhs)?;
+
+        Ok(self.hadamard_prod(rhs))
+    }
+
+    /// Computes the matrix product of `self` and `rhs` like
+    /// [`MatrixOp::dot_prod`], returning an `Error::DimensionMismatch` instead
+    /// of panicking if the x length of `self` is not equal to the y length of
+    /// `rhs`.
+    fn checked_dot<Other: MatrixRef<'a, T>>(
+        &self,
+        rhs: &Other,
+    ) -> Result<<Self as Mul<Other>>::Output>
+    where
+        Self: Mul<Other>,
+        <Self as Mul<Other>>::Output: MatrixOp<'a, T>,
+    {
+        check_product_dimensions(self, rhs)?;
+
+        Ok(self.mat_mul(rhs))
+    }
+
+    /// Returns the rank of the Matrix, which is the number of linearly
+    /// independent rows. Fraction-free elimination is used, so the result is
+    /// exact for integer element types, including unsigned ones.
+    fn rank(&self) -> usize
+    where
+        T: Field + PartialOrd,
+    {
+        let columns = self.get_x_len();
+        let rows = self.get_y_len();
+        let mut data = sign_magnitudes(self);
+        let mut previous = None;
+        let mut rank = 0;
+
+        for k in 0..columns {
+            if rank == rows {
+                break;
+            }
+
+            let pivot = match find_pivot(&data, columns, k, rank, rows) {
+                Some(pivot) => pivot,
+                None => continue,
+            };
+            swap_rows(&mut data, columns, pivot, rank);
+
+            for i in rank + 1..rows {
+                for j in k + 1..columns {
+                    let cell = data[i * columns + j] * data[rank * columns + k]
+                        - data[i * columns + k] * data[rank * columns + j];
+                    data[i * columns + j] = match previous {
+                        Some(previous) => cell / previous,
+                        None => cell,
+                    };
+                }
+                data[i * columns + k] = SignMagnitude::zero();
+            }
+
+            previous = Some(data[rank * columns + k]);
+            rank += 1;
+        }
+
+        rank
+    }
+
+    /// Returns the reduced row echelon form of the Matrix, found with
+    /// Gauss-Jordan elimination. Every pivot is scaled to one, so
+    /// `Error::NotRepresentable` is returned for element types without exact
+    /// division or without negative values, such as the primitive integers.
+    fn reduced_row_echelon(&self) -> Result<Self>
+    where
+        T: Field + PartialOrd,
+    {
+        check_exact_elimination::<T>()?;
+
+        let mut data = self.to_vec();
+        reduce_rows(&mut data, self.get_x_len(), self.get_y_len());
+
+        Ok(Self::mat_new_1d(&data, self.get_x_len(), self.get_y_len()))
+    }
+
+    /// Returns a basis for the null space of the Matrix as the columns of a
+    /// Matrix with as many rows as `self` has columns, so that
+    /// `self * null_space` is zero. Like
+    /// [`MatrixOp::reduced_row_echelon`], `Error::NotRepresentable` is
+    /// returned for element types without exact division or without negative
+    /// values.
+    fn null_space(&self) -> Result<HeapMatrix<T>>
+    where
+        T: Field + PartialOrd,
+    {
+        check_exact_elimination::<T>()?;
+
+        let columns = self.get_x_len();
+        let mut data = self.to_vec();
+        let pivots = reduce_rows(&mut data, columns, self.get_y_len());
+
+        let free: Vec<usize> = (0..columns).filter(|x| !pivots.contains(x)).collect();
+        let mut basis = vec![T::zero(); columns * free.len()];
+        for (index, f) in free.iter().enumerate() {
+            basis[f * free.len() + index] = T::one();
+            for (row, pivot) in pivots.iter().enumerate() {
+                basis[pivot * free.len() + index] = T::zero() - data[row * columns + f];
+            }
+        }
+
+        Ok(HeapMatrix::new(&basis, free.len(), columns))
+    }
+}
+
+pub trait SquareMatrix<'a, T: Semiring>: MatrixOp<'a, T> {
+    /// Returns the sum of the elements on the main diagonal of the Matrix.
+    fn trace(&self) -> Result<T> {
+        check_square(self)?;
+
+        let mut sum = T::zero();
+        for i in 0..self.get_x_len() {
+            sum += self.get_at_unchecked(i, i);
+        }
+
+        Ok(sum)
+    }
+
+    /// Returns the determinant of the Matrix. Fraction-free elimination is
+    /// used, so the result is exact for integer element types. For unsigned
+    /// element types, `Error::NotRepresentable` is returned if the determinant
+    /// is negative.
+    fn determinant(&self) -> Result<T>
+    where
+        T: Field + PartialOrd,
+    {
+        check_square(self)?;
+
+        let n = self.get_x_len();
+        let mut data = sign_magnitudes(self);
+        let mut negate = false;
+        let mut previous = None;
+
+        for k in 0..n {
+            let pivot = match find_pivot(&data, n, k, k, n) {
+                Some(pivot) => pivot,
+                None => return Ok(T::zero()),
+            };
+
+            if pivot != k {
+                swap_rows(&mut data, n, pivot, k);
+                negate = !negate;
+            }
+
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    let cell = data[i * n + j] * data[k * n + k]
+                        - data[i * n + k] * data[k * n + j];
+                    data[i * n + j] = match previous {
+                        Some(previous) => cell / previous,
+                        None => cell,
+                    };
+                }
+            }
+
+            previous = Some(data[k * n + k]);
+        }
+
+        let det = data[n * n - 1];
+        if negate {
+            (SignMagnitude::zero() - det).value()
+        } else {
+            det.value()
+        }
+    }
+
+    /// Computes the LU decomposition of the Matrix with partial pivoting, which
+    /// can be reused to solve linear systems against many right-hand sides.
+    fn lu(&self) -> Result<LuDecomposition<T>>
+    where
+        T: Field + PartialOrd,
+    {
+        LuDecomposition::new(self)
+    }
+
+    /// Computes the Cholesky decomposition of a symmetric positive-definite
+    /// Matrix, returning `Error::NotPositiveDefinite` if the Matrix is not
+    /// positive-definite.
+    fn cholesky(&self) -> Result<CholeskyDecomposition<T>>
+    where
+        T: Real,
+    {
+        CholeskyDecomposition::new(self)
+    }
+
+    /// Computes the eigenvalues and eigenvectors of a real symmetric Matrix,
+    /// returning `Error::NoConvergence` if the algorithm fails to converge.
+    fn symmetric_eigen(&self) -> Result<SymmetricEigen<T>>
+    where
+        T: Real,
+    {
+        SymmetricEigen::new(self)
+    }
 
-        let mut data = Vec::new();
-        let mut data_inner = Vec::new();
-        data_inner.resize(rhs.get_x_len(), T::default());
-        data.resize(self.get_y_len(), data_inner);
-
-        data.iter_mut()
-            .enumerate()
-            .map(|(row_index, y)| {
-                y.iter_mut()
-                    .enumerate()
-                    .map(|(column_index, x)| {
-                        *x = {
-                            let mut cell = T::default();
-                            for i in 0..rhs.get_y_len() {
-                                cell += self.get_at_unchecked(i, row_index)
-                                    * rhs.get_at_unchecked(column_index, i);
-                            }
-                            cell
-                        }
-                    })
-                    .last();
+    /// Computes the possibly complex eigenvalues of a general real Matrix,
+    /// returning `Error::NoConvergence` if the algorithm fails to converge.
+    fn eigen(&self) -> Result<Eigen<T>>
+    where
+        T: Real,
+    {
+        Eigen::new(self)
+    }
+
+    /// Returns the inverse of the Matrix, or `Error::Singular` if the Matrix
+    /// has no inverse. For integer element types the inverse is only returned
+    /// if every element of it is an integer and, for unsigned element types,
+    /// non-negative, otherwise `Error::NotRepresentable` is returned.
+    fn try_inverse(&self) -> Result<Self>
+    where
+        T: Field + PartialOrd,
+    {
+        check_square(self)?;
+
+        let n = self.get_x_len();
+        let width = 2 * n;
+        if self.to_vec().iter().all(Zero::is_zero) {
+            return Err(Error::Singular);
+        }
+
+        // Augment the Matrix with the identity, giving [A | I].
+        let mut data = vec![SignMagnitude::zero(); n * width];
+        for y in 0..n {
+            for x in 0..n {
+                data[y * width + x] = SignMagnitude::new(self.get_at_unchecked(x, y));
+            }
+            data[y * width + n + y] = SignMagnitude::one();
+        }
+
+        // Fraction-free Gauss-Jordan elimination reduces [A | I] to
+        // [d * I | d * A^-1], where d is the final pivot.
+        let mut previous = None;
+        for k in 0..n {
+            let pivot = find_pivot(&data, width, k, k, n).ok_or(Error::Singular)?;
+            swap_rows(&mut data, width, pivot, k);
+
+            for i in (0..n).filter(|i| *i != k) {
+                let factor = data[i * width + k];
+                for j in 0..width {
+                    let cell = data[k * width + k] * data[i * width + j]
+                        - factor * data[k * width + j];
+                    data[i * width + j] = match previous {
+                        Some(previous) => cell / previous,
+                        None => cell,
+                    };
+                }
+            }
+
+            previous = Some(data[k * width + k]);
+        }
+
+        // Division truncates for integer element types, in which case the
+        // inverse is only representable if every division is exact.
+        let d = data[n * width - n - 1];
+        let mut inverse = Vec::with_capacity(n * n);
+        for y in 0..n {
+            for x in n..width {
+                let cell = data[y * width + x];
+                let quotient = cell / d;
+                if T::TRUNCATES && quotient * d != cell {
+                    return Err(Error::NotRepresentable);
+                }
+                inverse.push(quotient.value()?);
+            }
+        }
+
+        Ok(Self::mat_new_1d(&inverse, n, n))
+    }
+}
+
+pub trait RealMatrix<'a, T: Real>: MatrixOp<'a, T> {
+    /// Computes the QR decomposition of the Matrix using Householder
+    /// reflections.
+    fn qr(&self) -> QrDecomposition<T> { QrDecomposition::new(self) }
+
+    /// Finds the `x` which minimises the 2-norm of `self * x - b`, returning
+    /// it together with the norm of the residual. See
+    /// [`QrDecomposition::least_squares`].
+    fn least_squares(&self, b: &[T]) -> Result<(Vec<T>, T)> { self.qr().least_squares(b) }
+
+    /// Computes the thin singular value decomposition of the Matrix, returning
+    /// `Error::NoConvergence` if the algorithm fails to converge.
+    fn svd(&self) -> Result<SingularValueDecomposition<T>> {
+        SingularValueDecomposition::new(self)
+    }
+
+    /// Returns the Moore-Penrose pseudo-inverse of the Matrix, which has the x
+    /// and y lengths of the Matrix swapped. See
+    /// [`SingularValueDecomposition::pseudo_inverse`].
+    fn pseudo_inverse<Res: MatrixAlloc<'a, T>>(&self) -> Result<Res> {
+        let pseudo_inverse = self.svd()?.pseudo_inverse();
+
+        Ok(Res::mat_new_1d(
+            pseudo_inverse.get_data(),
+            pseudo_inverse.get_x_len(),
+            pseudo_inverse.get_y_len(),
+        ))
+    }
+
+    /// Returns the numerical rank of the Matrix, which is the amount of
+    /// singular values greater than `tolerance`.
+    fn numerical_rank(&self, tolerance: T) -> Result<usize> {
+        Ok(self.svd()?.rank_with_tolerance(tolerance))
+    }
+
+    /// Returns the 2-norm of the Matrix, which is its largest singular value.
+    fn norm_2(&self) -> Result<T> { Ok(self.svd()?.norm_2()) }
+
+    /// Returns the 2-norm condition number of the Matrix.
+    fn condition_number(&self) -> Result<T> { Ok(self.svd()?.condition_number()) }
+}
+
+pub trait ComplexMatrix<'a, T: Ring>: MatrixOp<'a, Complex<T>> {
+    /// Returns the Matrix with every element replaced by its complex
+    /// conjugate.
+    fn conjugate(&self) -> Self {
+        let data: Vec<Complex<T>> = self.to_vec().into_iter().map(|x| x.conj()).collect();
+
+        Self::mat_new_1d(&data, self.get_x_len(), self.get_y_len())
+    }
+
+    /// Returns the conjugate transpose of the Matrix, which has the x and y
+    /// lengths of the Matrix swapped.
+    fn adjoint<Res: MatrixAlloc<'a, Complex<T>>>(&self) -> Res {
+        let columns = self.get_x_len();
+        let rows = self.get_y_len();
+
+        let mut data = Vec::with_capacity(columns * rows);
+        for x in 0..columns {
+            for y in 0..rows {
+                data.push(self.get_at_unchecked(x, y).conj());
+            }
+        }
+
+        Res::mat_new_1d(&data, rows, columns)
+    }
+
+    /// Returns whether the Matrix is equal to its own conjugate transpose.
+    fn is_hermitian(&self) -> bool {
+        let n = self.get_x_len();
+        if n != self.get_y_len() {
+            return false;
+        }
+
+        (0..n).all(|y| {
+            (y..n).all(|x| {
+                self.get_at_unchecked(x, y) == self.get_at_unchecked(y, x).conj()
             })
-            .last();
+        })
+    }
+}
+
+pub trait HStack<Rhs> {
+    /// The type of the Matrix which results from the concatenation.
+    type Output;
+
+    /// Concatenates `rhs` to the right of `self`, returning
+    /// `Error::DimensionMismatch` if the two do not have the same y length.
+    fn hstack(&self, rhs: &Rhs) -> Result<Self::Output>;
+}
+
+pub trait VStack<Rhs> {
+    /// The type of the Matrix which results from the concatenation.
+    type Output;
+
+    /// Concatenates `rhs` below `self`, returning `Error::DimensionMismatch`
+    /// if the two do not have the same x length.
+    fn vstack(&self, rhs: &Rhs) -> Result<Self::Output>;
+}
+
+/// Returns the row-major data of `lhs` and `rhs` placed side by side, or an
+/// `Error::DimensionMismatch` if they do not have the same y length.
+pub(crate) fn hstack_data<'a, 'b, T: Numeric>(
+    lhs: &impl MatrixRef<'a, T>,
+    rhs: &impl MatrixRef<'b, T>,
+) -> Result<Vec<T>> {
+    if lhs.get_y_len() != rhs.get_y_len() {
+        return Err(Error::DimensionMismatch {
+            expected: (rhs.get_x_len(), lhs.get_y_len()),
+            found: (rhs.get_x_len(), rhs.get_y_len()),
+        });
+    }
+
+    let mut data =
+        Vec::with_capacity((lhs.get_x_len() + rhs.get_x_len()) * lhs.get_y_len());
+    for y in 0..lhs.get_y_len() {
+        data.extend((0..lhs.get_x_len()).map(|x| lhs.get_at_unchecked(x, y)));
+        data.extend((0..rhs.get_x_len()).map(|x| rhs.get_at_unchecked(x, y)));
+    }
+
+    Ok(data)
+}
+
+/// Returns the row-major data of `rhs` placed below `lhs`, or an
+/// `Error::DimensionMismatch` if they do not have the same x length.
+pub(crate) fn vstack_data<'a, 'b, T: Numeric>(
+    lhs: &impl MatrixRef<'a, T>,
+    rhs: &impl MatrixRef<'b, T>,
+) -> Result<Vec<T>> {
+    if lhs.get_x_len() != rhs.get_x_len() {
+        return Err(Error::DimensionMismatch {
+            expected: (lhs.get_x_len(), rhs.get_y_len()),
+            found: (rhs.get_x_len(), rhs.get_y_len()),
+        });
+    }
+
+    let mut data = lhs.to_vec();
+    data.extend(rhs.to_vec());
+
+    Ok(data)
+}
+
+/// Returns an `Error::NotSquare` if `mat` is not a non-empty square Matrix.
+pub(crate) fn check_square<'a, T: Numeric>(mat: &impl MatrixRef<'a, T>) -> Result<()> {
+    if mat.get_x_len() != mat.get_y_len() || mat.get_x_len() == 0 {
+        return Err(Error::NotSquare {
+            dimensions: (mat.get_x_len(), mat.get_y_len()),
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns `Error::NotRepresentable` unless Gauss-Jordan elimination is exact
+/// for `T`, which needs exact division and negative values.
+pub(crate) fn check_exact_elimination<T: Field>() -> Result<()> {
+    if T::TRUNCATES || T::UNSIGNED {
+        return Err(Error::NotRepresentable);
+    }
+
+    Ok(())
+}
 
-        Self::mat_new_vec(data)
+/// Reduces the `rows x columns` row-major `data` to reduced row echelon form
+/// in place, returning the column of the pivot in each non-zero row.
+pub(crate) fn reduce_rows<T: Field + PartialOrd>(
+    data: &mut [T],
+    columns: usize,
+    rows: usize,
+) -> Vec<usize> {
+    let mut pivots = Vec::new();
+
+    for k in 0..columns {
+        let row = pivots.len();
+        if row == rows {
+            break;
+        }
+
+        let pivot = match find_pivot(data, columns, k, row, rows) {
+            Some(pivot) => pivot,
+            None => continue,
+        };
+        swap_rows(data, columns, pivot, row);
+
+        let scale = data[row * columns + k];
+        for j in k..columns {
+            data[row * columns + j] /= scale;
+        }
+
+        for i in (0..rows).filter(|i| *i != row) {
+            let factor = data[i * columns + k];
+            if factor.is_zero() {
+                continue;
+            }
+            for j in k..columns {
+                let cell = factor * data[row * columns + j];
+                data[i * columns + j] -= cell;
+            }
+        }
+
+        pivots.push(k);
     }
+
+    pivots
+}
+
+/// Returns the index of the row in `from..to` with the largest magnitude in
+/// `column`, or `None` if every element in that range is zero.
+pub(crate) fn find_pivot<T: Ring + PartialOrd>(
+    data: &[T],
+    width: usize,
+    column: usize,
+    from: usize,
+    to: usize,
+) -> Option<usize> {
+    let magnitude = |x: T| {
+        if x < T::zero() {
+            T::zero() - x
+        } else {
+            x
+        }
+    };
+
+    (from..to)
+        .filter(|y| data[y * width + column] != T::zero())
+        .fold(None, |best: Option<usize>, y| match best {
+            Some(best)
+                if magnitude(data[best * width + column])
+                    >= magnitude(data[y * width + column]) =>
+            {
+                Some(best)
+            }
+            _ => Some(y),
+        })
+}
+
+/// Copies the elements of `mat` in row-major order as SignMagnitudes, so that
+/// fraction-free elimination on them cannot overflow unsigned element types.
+fn sign_magnitudes<'a, T: Field + PartialOrd>(
+    mat: &impl MatrixRef<'a, T>,
+) -> Vec<SignMagnitude<T>> {
+    mat.to_vec().into_iter().map(SignMagnitude::new).collect()
+}
+
+/// Swaps the rows `a` and `b` of the row-major `data` with the given width.
+pub(crate) fn swap_rows<T>(data: &mut [T], width: usize, a: usize, b: usize) {
+    if a == b {
+        return;
+    }
+
+    for x in 0..width {
+        data.swap(a * width + x, b * width + x);
+    }
+}
+
+/// The side length of the square blocks which transposes are performed in, so
+/// that both the rows being read and the rows being written stay in cache.
+const TRANSPOSE_BLOCK: usize = 32;
+
+/// Writes the transpose of the `rows x columns` row-major `src` into `dst`,
+/// which becomes a `columns x rows` row-major Matrix.
+pub(crate) fn transpose_into<T: Copy>(
+    src: &[T],
+    dst: &mut [T],
+    columns: usize,
+    rows: usize,
+) {
+    for block_y in (0..rows).step_by(TRANSPOSE_BLOCK) {
+        for block_x in (0..columns).step_by(TRANSPOSE_BLOCK) {
+            for y in block_y..rows.min(block_y + TRANSPOSE_BLOCK) {
+                for x in block_x..columns.min(block_x + TRANSPOSE_BLOCK) {
+                    dst[x * rows + y] = src[y * columns + x];
+                }
+            }
+        }
+    }
+}
+
+/// Transposes the `rows x columns` row-major `data` in place, leaving it as a
+/// `columns x rows` row-major Matrix.
+pub(crate) fn transpose_in_place<T>(data: &mut [T], columns: usize, rows: usize) {
+    if columns == rows {
+        let n = columns;
+        for block_y in (0..n).step_by(TRANSPOSE_BLOCK) {
+            for block_x in (block_y..n).step_by(TRANSPOSE_BLOCK) {
+                for y in block_y..n.min(block_y + TRANSPOSE_BLOCK) {
+                    for x in block_x.max(y + 1)..n.min(block_x + TRANSPOSE_BLOCK) {
+                        data.swap(y * n + x, x * n + y);
+                    }
+                }
+            }
+        }
+
+        return;
+    }
+
+    // The element at index i moves to index i * rows mod (len - 1), so each
+    // cycle of that permutation is followed once, with the first and last
+    // elements never moving.
+    let last = data.len().saturating_sub(1);
+    let mut visited = vec![false; data.len()];
+    for start in 1..last {
+        if visited[start] {
+            continue;
+        }
+
+        let mut index = start;
+        loop {
+            visited[index] = true;
+            let next = index * rows % last;
+            if next == start {
+                break;
+            }
+            data.swap(start, next);
+            index = next;
+        }
+    }
+}
+
+/// Returns the element at (x, y) of a dense Matrix, panicking if the
+/// coordinates are out of range.
+pub(crate) fn get_strided<'a, T: Numeric>(
+    mat: &(impl DenseMatrixRef<'a, T> + ?Sized),
+    x: usize,
+    y: usize,
+) -> T {
+    assert!(
+        x < mat.get_x_len() && y < mat.get_y_len(),
+        "Index ({}, {}) is out of range",
+        x,
+        y
+    );
+
+    let (x_stride, y_stride) = mat.get_strides();
+    mat.get_data()[x * x_stride + y * y_stride]
+}
+
+/// Copies the elements of a dense Matrix into a Vec<T> in row-major order,
+/// copying the inner data directly when it is contiguous.
+pub(crate) fn strided_to_vec<'a, T: Numeric>(
+    mat: &(impl DenseMatrixRef<'a, T> + ?Sized),
+) -> Vec<T> {
+    if mat.is_contiguous() {
+        return mat.get_data().to_vec();
+    }
+
+    (0..mat.get_y_len())
+        .flat_map(|y| (0..mat.get_x_len()).map(move |x| get_strided(mat, x, y)))
+        .collect()
+}
+
+/// Returns the index in the inner data of `mat` at which the row at
+/// `row_index` starts, panicking if the row does not exist or its elements are
+/// not adjacent.
+fn row_start<'a, T: Numeric>(
+    mat: &(impl DenseMatrixRef<'a, T> + ?Sized),
+    row_index: usize,
+) -> usize {
+    assert!(
+        row_index < mat.get_y_len(),
+        "Row {} is out of range",
+        row_index
+    );
+
+    let (x_stride, y_stride) = mat.get_strides();
+    assert!(
+        x_stride == 1 || mat.get_x_len() <= 1,
+        "The elements of a row are not adjacent"
+    );
+
+    row_index * y_stride
+}
+
+/// Returns the range of the inner data of `mat` which a view of the
+/// `columns x rows` block at (x, y) covers, or `Error::OutOfRange` if the
+/// block does not fit in `mat`.
+fn view_range<'a, T: Numeric>(
+    mat: &(impl DenseMatrixRef<'a, T> + ?Sized),
+    x: usize,
+    y: usize,
+    columns: usize,
+    rows: usize,
+) -> Result<(usize, usize)> {
+    if x + columns > mat.get_x_len() || y + rows > mat.get_y_len() {
+        return Err(Error::OutOfRange {
+            index: (x + columns, y + rows),
+            bounds: (mat.get_x_len(), mat.get_y_len()),
+        });
+    }
+
+    if columns == 0 || rows == 0 {
+        return Ok((0, 0));
+    }
+
+    let (x_stride, y_stride) = mat.get_strides();
+    let start = x * x_stride + y * y_stride;
+    let end = (x + columns - 1) * x_stride + (y + rows - 1) * y_stride + 1;
+
+    Ok((start, end))
+}
+
+/// Returns an `Error::DimensionMismatch` if `lhs` and `rhs` do not have the
+/// same x and y lengths.
+pub(crate) fn check_same_dimensions<'a, 'b, T: Numeric>(
+    lhs: &(impl MatrixRef<'a, T> + ?Sized),
+    rhs: &impl MatrixRef<'b, T>,
+) -> Result<()> {
+    let expected = (lhs.get_x_len(), lhs.get_y_len());
+    let found = (rhs.get_x_len(), rhs.get_y_len());
+
+    if expected != found {
+        return Err(Error::DimensionMismatch { expected, found });
+    }
+
+    Ok(())
+}
+
+/// Returns an `Error::DimensionMismatch` if the x length of `lhs` is not equal
+/// to the y length of `rhs`, meaning the two cannot be multiplied together.
+pub(crate) fn check_product_dimensions<'a, 'b, T: Numeric>(
+    lhs: &impl MatrixRef<'a, T>,
+    rhs: &impl MatrixRef<'b, T>,
+) -> Result<()> {
+    if lhs.get_x_len() != rhs.get_y_len() {
+        return Err(Error::DimensionMismatch {
+            expected: (rhs.get_x_len(), lhs.get_x_len()),
+            found: (rhs.get_x_len(), rhs.get_y_len()),
+        });
+    }
+
+    Ok(())
+}
+
+/// Maps every column of `rhs` through `f`, which turns a column of length `n`
+/// into a column of the same length, and collects the results into a new
+/// HeapMatrix. Returns an `Error::DimensionMismatch` if `rhs` does not have `n`
+/// rows.
+pub(crate) fn map_columns<'a, T: Numeric>(
+    n: usize,
+    rhs: &impl MatrixRef<'a, T>,
+    f: impl Fn(&[T]) -> Result<Vec<T>>,
+) -> Result<HeapMatrix<T>> {
+    let columns = rhs.get_x_len();
+    if rhs.get_y_len() != n {
+        return Err(Error::DimensionMismatch {
+            expected: (columns, n),
+            found: (columns, rhs.get_y_len()),
+        });
+    }
+
+    let mut data = vec![T::zero(); columns * n];
+    for x in 0..columns {
+        let column: Vec<T> = (0..n).map(|y| rhs.get_at_unchecked(x, y)).collect();
+
+        for (y, cell) in f(&column)?.into_iter().enumerate() {
+            data[y * columns + x] = cell;
+        }
+    }
+
+    Ok(HeapMatrix::new(&data, columns, n))
+}
+
+/// Returns an `Error::IncorrectLength` if the vector `x` does not have `n`
+/// elements.
+pub(crate) fn check_vec_len<T>(n: usize, x: &[T]) -> Result<()> {
+    if x.len() != n {
+        return Err(Error::IncorrectLength {
+            expected: n,
+            found: x.len(),
+        });
+    }
+
+    Ok(())
 }
@@ -0,0 +1,118 @@
+use crate::common::{
+    Division, Field, Numeric, One, Real, Ring, Semiring, Subtraction, Zero,
+};
+use std::{
+    fmt::{Display, Formatter},
+    ops::*,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A complex number with real and imaginary parts of type `T`, which can be
+/// used as the element type of a Matrix.
+pub struct Complex<T: Numeric> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T: Numeric> Complex<T> {
+    /// Creates a new complex number from its real and imaginary parts.
+    pub const fn new(re: T, im: T) -> Self { Self { re, im } }
+}
+
+impl<T: Ring> Complex<T> {
+    /// Returns the complex conjugate of the number.
+    pub fn conj(self) -> Self { Self::new(self.re, T::zero() - self.im) }
+
+    /// Returns the square of the absolute value of the number.
+    pub fn norm_sqr(self) -> T { self.re * self.re + self.im * self.im }
+}
+
+impl<T: Real> Complex<T> {
+    /// Returns the absolute value of the number.
+    pub fn norm(self) -> T { self.norm_sqr().sqrt() }
+}
+
+impl<T: Numeric> From<T> for Complex<T> {
+    fn from(re: T) -> Self { Self::new(re, T::zero()) }
+}
+
+impl<T: Numeric> Zero for Complex<T> {
+    fn zero() -> Self { Self::new(T::zero(), T::zero()) }
+    fn is_zero(&self) -> bool { self.re.is_zero() && self.im.is_zero() }
+}
+
+impl<T: Ring> One for Complex<T> {
+    fn one() -> Self { Self::new(T::one(), T::zero()) }
+}
+
+impl<T: Semiring> Add for Complex<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T: Ring> Sub for Complex<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T: Ring> Mul for Complex<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<T: Field> Div for Complex<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let denominator = rhs.norm_sqr();
+
+        Self::new(
+            (self.re * rhs.re + self.im * rhs.im) / denominator,
+            (self.im * rhs.re - self.re * rhs.im) / denominator,
+        )
+    }
+}
+
+impl<T: Ring> Subtraction for Complex<T> {
+    const UNSIGNED: bool = T::UNSIGNED;
+}
+
+impl<T: Field> Division for Complex<T> {
+    // The real and imaginary parts are divided by the squared norm, so the
+    // division is exact exactly when it is for `T`.
+    const TRUNCATES: bool = T::TRUNCATES;
+}
+
+impl<T: Semiring> AddAssign for Complex<T> {
+    fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+}
+
+impl<T: Ring> SubAssign for Complex<T> {
+    fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+}
+
+impl<T: Ring> MulAssign for Complex<T> {
+    fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+}
+
+impl<T: Field> DivAssign for Complex<T> {
+    fn div_assign(&mut self, rhs: Self) { *self = *self / rhs; }
+}
+
+impl<T: Numeric + Display> Display for Complex<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{:+}i", self.re, self.im)
+    }
+}
@@ -0,0 +1,7 @@
+pub mod complex;
+pub mod modp;
+pub mod ratio;
+
+pub use complex::*;
+pub use modp::*;
+pub use ratio::*;
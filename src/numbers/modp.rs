@@ -0,0 +1,158 @@
+use crate::common::{Division, One, Subtraction, Zero};
+use std::{
+    fmt::{Display, Formatter},
+    ops::*,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An integer modulo `P`, which can be used as the element type of a Matrix
+/// over the finite field GF(P).
+///
+/// `P` should be prime for every non-zero element to have an inverse. If it
+/// is not, dividing by an element which shares a factor with `P` panics.
+pub struct ModP<const P: u64> {
+    value: u64,
+}
+
+impl<const P: u64> ModP<P> {
+    /// Creates a new element equal to `value` modulo `P`.
+    pub const fn new(value: u64) -> Self { Self { value: value % P } }
+
+    /// Creates a new element equal to `value` modulo `P`, mapping negative
+    /// values to their positive representative.
+    pub const fn from_i64(value: i64) -> Self {
+        // P may not fit in an i64, so the reduction is done in i128.
+        Self {
+            value: (value as i128).rem_euclid(P as i128) as u64,
+        }
+    }
+
+    /// Gets the representative of the element in `0..P`.
+    pub const fn value(&self) -> u64 { self.value }
+
+    /// Raises the element to the power of `exponent`.
+    pub fn pow(self, mut exponent: u64) -> Self {
+        let mut base = self;
+        let mut result = Self::one();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exponent >>= 1;
+        }
+
+        result
+    }
+
+    /// Returns the multiplicative inverse of the element, or `None` if it
+    /// shares a factor with `P`, which for prime `P` only zero does.
+    pub fn inverse(self) -> Option<Self> {
+        // The extended Euclidean algorithm, tracking only the coefficient of
+        // the element.
+        let (mut r, mut next_r) = (P as i128, self.value as i128);
+        let (mut t, mut next_t) = (0i128, 1i128);
+        while next_r != 0 {
+            let quotient = r / next_r;
+            let remainder = r - quotient * next_r;
+            r = next_r;
+            next_r = remainder;
+            let coefficient = t - quotient * next_t;
+            t = next_t;
+            next_t = coefficient;
+        }
+
+        if r != 1 {
+            return None;
+        }
+
+        Some(Self {
+            value: t.rem_euclid(P as i128) as u64,
+        })
+    }
+}
+
+impl<const P: u64> From<u64> for ModP<P> {
+    fn from(value: u64) -> Self { Self::new(value) }
+}
+
+impl<const P: u64> Zero for ModP<P> {
+    fn zero() -> Self { Self::new(0) }
+    fn is_zero(&self) -> bool { self.value == 0 }
+}
+
+impl<const P: u64> One for ModP<P> {
+    fn one() -> Self { Self::new(1) }
+}
+
+impl<const P: u64> Add for ModP<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            value: ((self.value as u128 + rhs.value as u128) % P as u128) as u64,
+        }
+    }
+}
+
+impl<const P: u64> Sub for ModP<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            value: ((self.value as u128 + P as u128 - rhs.value as u128) % P as u128)
+                as u64,
+        }
+    }
+}
+
+impl<const P: u64> Mul for ModP<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            value: ((self.value as u128 * rhs.value as u128) % P as u128) as u64,
+        }
+    }
+}
+
+impl<const P: u64> Div for ModP<P> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs
+            .inverse()
+            .unwrap_or_else(|| panic!("{} has no inverse modulo {}", rhs.value, P))
+    }
+}
+
+impl<const P: u64> Subtraction for ModP<P> {
+    const UNSIGNED: bool = false;
+}
+
+impl<const P: u64> Division for ModP<P> {
+    const TRUNCATES: bool = false;
+}
+
+impl<const P: u64> AddAssign for ModP<P> {
+    fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+}
+
+impl<const P: u64> SubAssign for ModP<P> {
+    fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+}
+
+impl<const P: u64> MulAssign for ModP<P> {
+    fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+}
+
+impl<const P: u64> DivAssign for ModP<P> {
+    fn div_assign(&mut self, rhs: Self) { *self = *self / rhs; }
+}
+
+impl<const P: u64> Display for ModP<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
@@ -0,0 +1,185 @@
+use crate::common::{Division, Integer, One, Subtraction, Zero};
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+    ops::*,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// An exact rational number with a numerator and denominator of the integer
+/// type `T`, which can be used as the element type of a Matrix to compute
+/// determinants, inverses and solutions without rounding error.
+///
+/// Ratios are always kept in lowest terms with a positive denominator, so two
+/// Ratios are equal exactly when their numerators and denominators are. The
+/// arithmetic overflows like `T` does, so a wider integer type such as `i128`
+/// can be used when the intermediate values grow large.
+pub struct Ratio<T: Integer> {
+    numer: T,
+    denom: T,
+}
+
+impl<T: Integer> Ratio<T> {
+    /// Creates a new Ratio equal to `numer / denom` in lowest terms, panicking
+    /// if `denom` is zero.
+    pub fn new(numer: T, denom: T) -> Self {
+        assert!(!denom.is_zero(), "Ratio has a zero denominator");
+
+        let divisor = gcd(numer, denom);
+        let (numer, denom) = (numer / divisor, denom / divisor);
+
+        if denom < T::zero() {
+            Self {
+                numer: T::zero() - numer,
+                denom: T::zero() - denom,
+            }
+        } else {
+            Self { numer, denom }
+        }
+    }
+
+    /// Creates a new Ratio equal to the integer `n`.
+    pub fn from_integer(n: T) -> Self {
+        Self {
+            numer: n,
+            denom: T::one(),
+        }
+    }
+
+    /// Gets the numerator of the Ratio in lowest terms.
+    pub fn numer(&self) -> T { self.numer }
+
+    /// Gets the denominator of the Ratio in lowest terms, which is always
+    /// positive.
+    pub fn denom(&self) -> T { self.denom }
+
+    /// Returns whether the Ratio is an integer.
+    pub fn is_integer(&self) -> bool { self.denom == T::one() }
+
+    /// Returns the reciprocal of the Ratio, panicking if it is zero.
+    pub fn recip(self) -> Self { Self::new(self.denom, self.numer) }
+}
+
+/// Returns the non-negative greatest common divisor of `a` and `b`, or one if
+/// both are zero.
+fn gcd<T: Integer>(mut a: T, mut b: T) -> T {
+    while !b.is_zero() {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+
+    if a.is_zero() {
+        T::one()
+    } else if a < T::zero() {
+        T::zero() - a
+    } else {
+        a
+    }
+}
+
+impl<T: Integer> From<T> for Ratio<T> {
+    fn from(n: T) -> Self { Self::from_integer(n) }
+}
+
+impl<T: Integer> Zero for Ratio<T> {
+    fn zero() -> Self { Self::from_integer(T::zero()) }
+    fn is_zero(&self) -> bool { self.numer.is_zero() }
+}
+
+impl<T: Integer> One for Ratio<T> {
+    fn one() -> Self { Self::from_integer(T::one()) }
+}
+
+impl<T: Integer> PartialOrd for Ratio<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl<T: Integer> Ord for Ratio<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Both denominators are positive, so cross multiplying keeps the order.
+        (self.numer * other.denom).cmp(&(other.numer * self.denom))
+    }
+}
+
+impl<T: Integer> Add for Ratio<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let divisor = gcd(self.denom, rhs.denom);
+
+        Self::new(
+            self.numer * (rhs.denom / divisor) + rhs.numer * (self.denom / divisor),
+            self.denom / divisor * rhs.denom,
+        )
+    }
+}
+
+impl<T: Integer> Sub for Ratio<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let divisor = gcd(self.denom, rhs.denom);
+
+        Self::new(
+            self.numer * (rhs.denom / divisor) - rhs.numer * (self.denom / divisor),
+            self.denom / divisor * rhs.denom,
+        )
+    }
+}
+
+impl<T: Integer> Mul for Ratio<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        // Cancelling common factors first keeps the intermediate products small.
+        let lhs_divisor = gcd(self.numer, rhs.denom);
+        let rhs_divisor = gcd(rhs.numer, self.denom);
+
+        Self::new(
+            (self.numer / lhs_divisor) * (rhs.numer / rhs_divisor),
+            (self.denom / rhs_divisor) * (rhs.denom / lhs_divisor),
+        )
+    }
+}
+
+impl<T: Integer> Div for Ratio<T> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output { self * rhs.recip() }
+}
+
+impl<T: Integer> Subtraction for Ratio<T> {
+    const UNSIGNED: bool = T::UNSIGNED;
+}
+
+impl<T: Integer> Division for Ratio<T> {
+    const TRUNCATES: bool = false;
+}
+
+impl<T: Integer> AddAssign for Ratio<T> {
+    fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+}
+
+impl<T: Integer> SubAssign for Ratio<T> {
+    fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+}
+
+impl<T: Integer> MulAssign for Ratio<T> {
+    fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+}
+
+impl<T: Integer> DivAssign for Ratio<T> {
+    fn div_assign(&mut self, rhs: Self) { *self = *self / rhs; }
+}
+
+impl<T: Integer + Display> Display for Ratio<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
@@ -1 +1,4 @@
-pub use crate::{common::*, error::*, implementations::*, matrix::*};
+pub use crate::{
+    common::*, decompositions::*, error::*, implementations::*, iterators::*, matrix::*,
+    numbers::*, solvers::*,
+};
@@ -0,0 +1,106 @@
+use crate::{
+    common::Real,
+    error::{Error, Result},
+    solvers::{
+        axpy, check_system, dot, norm, LinearOperator, Preconditioner, SolverOptions,
+        SolverReport,
+    },
+};
+
+/// Solves `A * x = b` for a general square `A` with the right-preconditioned
+/// biconjugate gradient stabilised method (BiCGSTAB), starting from `x = 0`.
+///
+/// Returns `Error::NotSquare` or `Error::IncorrectLength` if the system is
+/// malformed, or `Error::NoConvergence` if the residual has not reached the
+/// tolerance within the iteration limit or the method breaks down.
+pub fn bicgstab<'a, T: 'a + Real>(
+    a: &impl LinearOperator<'a, T>,
+    b: &[T],
+    preconditioner: &impl Preconditioner<T>,
+    options: &SolverOptions<T>,
+) -> Result<SolverReport<T>> {
+    let b_norm = check_system(a, b)?;
+    let threshold = options.tolerance * b_norm;
+
+    let n = b.len();
+    let mut x = vec![T::zero(); n];
+    let mut r = b.to_vec();
+    if b_norm <= threshold {
+        return Ok(SolverReport {
+            solution: x,
+            iterations: 0,
+            residual_norm: b_norm,
+        });
+    }
+
+    let shadow = r.clone();
+    let mut rho = T::one();
+    let mut alpha = T::one();
+    let mut omega = T::one();
+    let mut p = vec![T::zero(); n];
+    let mut v = vec![T::zero(); n];
+
+    for iteration in 1..=options.max_iterations {
+        let next_rho = dot(&shadow, &r);
+        if next_rho.is_zero() || omega.is_zero() {
+            return Err(Error::NoConvergence {
+                iterations: iteration,
+            });
+        }
+
+        let beta = (next_rho / rho) * (alpha / omega);
+        rho = next_rho;
+        for ((p, r), v) in p.iter_mut().zip(&r).zip(&v) {
+            *p = *r + beta * (*p - omega * *v);
+        }
+
+        let y = preconditioner.apply(&p);
+        v = a.apply(&y);
+        let shadow_v = dot(&shadow, &v);
+        if shadow_v.is_zero() {
+            return Err(Error::NoConvergence {
+                iterations: iteration,
+            });
+        }
+        alpha = rho / shadow_v;
+
+        // r becomes the intermediate residual s.
+        axpy(T::zero() - alpha, &v, &mut r);
+        axpy(alpha, &y, &mut x);
+
+        let residual_norm = norm(&r);
+        if residual_norm <= threshold {
+            return Ok(SolverReport {
+                solution: x,
+                iterations: iteration,
+                residual_norm,
+            });
+        }
+
+        let z = preconditioner.apply(&r);
+        let t = a.apply(&z);
+        let tt = dot(&t, &t);
+        if tt.is_zero() {
+            return Err(Error::NoConvergence {
+                iterations: iteration,
+            });
+        }
+        omega = dot(&t, &r) / tt;
+
+        axpy(omega, &z, &mut x);
+        axpy(T::zero() - omega, &t, &mut r);
+
+        let residual_norm = norm(&r);
+        if residual_norm <= threshold {
+            return Ok(SolverReport {
+                solution: x,
+                iterations: iteration,
+                residual_norm,
+            });
+        }
+    }
+
+    Err(Error::NoConvergence {
+        iterations: options.max_iterations,
+    })
+}
@@ -0,0 +1,80 @@
+use crate::{
+    common::Real,
+    error::{Error, Result},
+    solvers::{
+        axpy, check_system, dot, norm, LinearOperator, Preconditioner, SolverOptions,
+        SolverReport,
+    },
+};
+
+/// Solves `A * x = b` for a symmetric positive-definite `A` with the
+/// preconditioned conjugate gradient method, starting from `x = 0`. The
+/// preconditioner must also be symmetric positive-definite. The Jacobi
+/// preconditioner always is for such an `A`. The ILU(0) preconditioner usually
+/// is when the sparsity pattern of `A` is symmetric, but its pivots are not
+/// guaranteed to stay positive.
+///
+/// Returns `Error::NotSquare` or `Error::IncorrectLength` if the system is
+/// malformed, or `Error::NoConvergence` if the residual has not reached the
+/// tolerance within the iteration limit or the method breaks down, which
+/// happens when `A` is not positive-definite.
+pub fn conjugate_gradient<'a, T: 'a + Real>(
+    a: &impl LinearOperator<'a, T>,
+    b: &[T],
+    preconditioner: &impl Preconditioner<T>,
+    options: &SolverOptions<T>,
+) -> Result<SolverReport<T>> {
+    let b_norm = check_system(a, b)?;
+    let threshold = options.tolerance * b_norm;
+
+    let mut x = vec![T::zero(); b.len()];
+    let mut r = b.to_vec();
+    let mut residual_norm = b_norm;
+    if residual_norm <= threshold {
+        return Ok(SolverReport {
+            solution: x,
+            iterations: 0,
+            residual_norm,
+        });
+    }
+
+    let mut z = preconditioner.apply(&r);
+    let mut p = z.clone();
+    let mut rz = dot(&r, &z);
+
+    for iteration in 1..=options.max_iterations {
+        let ap = a.apply(&p);
+        let curvature = dot(&p, &ap);
+        if curvature <= T::zero() {
+            return Err(Error::NoConvergence {
+                iterations: iteration,
+            });
+        }
+
+        let alpha = rz / curvature;
+        axpy(alpha, &p, &mut x);
+        axpy(T::zero() - alpha, &ap, &mut r);
+
+        residual_norm = norm(&r);
+        if residual_norm <= threshold {
+            return Ok(SolverReport {
+                solution: x,
+                iterations: iteration,
+                residual_norm,
+            });
+        }
+
+        z = preconditioner.apply(&r);
+        let next_rz = dot(&r, &z);
+        let beta = next_rz / rz;
+        rz = next_rz;
+
+        for (p, z) in p.iter_mut().zip(&z) {
+            *p = *z + beta * *p;
+        }
+    }
+
+    Err(Error::NoConvergence {
+        iterations: options.max_iterations,
+    })
+}
@@ -0,0 +1,119 @@
+use crate::{
+    common::Real,
+    error::{Error, Result},
+    solvers::{
+        axpy, check_system, dot, norm, LinearOperator, Preconditioner, SolverOptions,
+        SolverReport,
+    },
+};
+
+/// Solves `A * x = b` for a general square `A` with the right-preconditioned
+/// generalised minimal residual method (GMRES), starting from `x = 0` and
+/// restarting every `options.restart` iterations to bound the memory used by
+/// the Krylov basis.
+///
+/// Returns `Error::NotSquare` or `Error::IncorrectLength` if the system is
+/// malformed, or `Error::NoConvergence` if the residual has not reached the
+/// tolerance within the iteration limit.
+pub fn gmres<'a, T: 'a + Real>(
+    a: &impl LinearOperator<'a, T>,
+    b: &[T],
+    preconditioner: &impl Preconditioner<T>,
+    options: &SolverOptions<T>,
+) -> Result<SolverReport<T>> {
+    let b_norm = check_system(a, b)?;
+    let threshold = options.tolerance * b_norm;
+    let restart = options.restart.max(1);
+
+    let mut x = vec![T::zero(); b.len()];
+    let mut iterations = 0;
+
+    loop {
+        let mut r = a.apply(&x);
+        for (r, b) in r.iter_mut().zip(b) {
+            *r = *b - *r;
+        }
+
+        let residual_norm = norm(&r);
+        if residual_norm <= threshold {
+            return Ok(SolverReport {
+                solution: x,
+                iterations,
+                residual_norm,
+            });
+        }
+        if iterations >= options.max_iterations {
+            return Err(Error::NoConvergence { iterations });
+        }
+
+        let inverse_norm = T::one() / residual_norm;
+        let mut basis = vec![r.iter().map(|r| *r * inverse_norm).collect::<Vec<_>>()];
+        // The columns of the Hessenberg matrix, reduced to upper triangular
+        // form by the Givens rotations as they are added.
+        let mut hessenberg: Vec<Vec<T>> = Vec::with_capacity(restart);
+        let mut rotations: Vec<(T, T)> = Vec::with_capacity(restart);
+        let mut g = vec![residual_norm];
+
+        while hessenberg.len() < restart && iterations < options.max_iterations {
+            iterations += 1;
+            let j = hessenberg.len();
+
+            let mut w = a.apply(&preconditioner.apply(&basis[j]));
+            let mut column = Vec::with_capacity(j + 2);
+            for v in &basis {
+                let h = dot(&w, v);
+                axpy(T::zero() - h, v, &mut w);
+                column.push(h);
+            }
+            let w_norm = norm(&w);
+            column.push(w_norm);
+
+            for (i, (cos, sin)) in rotations.iter().enumerate() {
+                let upper = column[i];
+                let lower = column[i + 1];
+                column[i] = *cos * upper + *sin * lower;
+                column[i + 1] = *cos * lower - *sin * upper;
+            }
+
+            let radius = (column[j] * column[j] + column[j + 1] * column[j + 1]).sqrt();
+            let (cos, sin) = if radius.is_zero() {
+                (T::one(), T::zero())
+            } else {
+                (column[j] / radius, column[j + 1] / radius)
+            };
+            column[j] = radius;
+            column.pop();
+            rotations.push((cos, sin));
+            g.push(T::zero() - sin * g[j]);
+            g[j] = cos * g[j];
+            hessenberg.push(column);
+
+            // A zero w means the Krylov subspace contains the exact solution.
+            if g[j + 1].abs() <= threshold || w_norm.is_zero() {
+                break;
+            }
+
+            let inverse_norm = T::one() / w_norm;
+            basis.push(w.iter().map(|w| *w * inverse_norm).collect());
+        }
+
+        let k = hessenberg.len();
+        let mut y = g[..k].to_vec();
+        for i in (0..k).rev() {
+            for j in i + 1..k {
+                let cell = hessenberg[j][i] * y[j];
+                y[i] -= cell;
+            }
+            if hessenberg[i][i].is_zero() {
+                return Err(Error::NoConvergence { iterations });
+            }
+            y[i] /= hessenberg[i][i];
+        }
+
+        let mut update = vec![T::zero(); x.len()];
+        for (y, v) in y.iter().zip(&basis) {
+            axpy(*y, v, &mut update);
+        }
+        axpy(T::one(), &preconditioner.apply(&update), &mut x);
+    }
+}
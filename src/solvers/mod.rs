@@ -0,0 +1,115 @@
+pub mod bicgstab;
+pub mod conjugate_gradient;
+pub mod gmres;
+pub mod preconditioners;
+
+pub use bicgstab::*;
+pub use conjugate_gradient::*;
+pub use gmres::*;
+pub use preconditioners::*;
+
+use crate::{
+    common::{Real, Semiring},
+    error::{Error, Result},
+    implementations::{CscMatrix, HeapMatrix, SparseMatrix},
+    matrix::*,
+};
+
+/// A Matrix which can be multiplied by a vector efficiently, which is all that
+/// the iterative solvers need from the Matrix they are solving.
+pub trait LinearOperator<'a, T: 'a + Semiring>: MatrixRef<'a, T> {
+    /// Multiplies the Matrix by the vector `x`, panicking if `x` does not have
+    /// as many elements as the Matrix has columns.
+    fn apply(&self, x: &[T]) -> Vec<T>;
+}
+
+impl<'a, T: 'a + Semiring> LinearOperator<'a, T> for SparseMatrix<T> {
+    fn apply(&self, x: &[T]) -> Vec<T> {
+        self.mul_vec(x).unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+impl<'a, T: 'a + Semiring> LinearOperator<'a, T> for CscMatrix<T> {
+    fn apply(&self, x: &[T]) -> Vec<T> {
+        self.mul_vec(x).unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+impl<'a, T: 'a + Semiring> LinearOperator<'a, T> for HeapMatrix<T> {
+    fn apply(&self, x: &[T]) -> Vec<T> {
+        assert_eq!(x.len(), self.x_len, "Vector has the wrong length");
+
+        (0..self.y_len)
+            .map(|y| {
+                self.row(y)
+                    .zip(x)
+                    .fold(T::zero(), |sum, (cell, x)| sum + *cell * *x)
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The stopping criteria of an iterative solver.
+pub struct SolverOptions<T: Real> {
+    /// The solver stops once the residual norm `||b - A * x||` is at most
+    /// `tolerance * ||b||`.
+    pub tolerance: T,
+    /// The solver returns `Error::NoConvergence` if it has not stopped after
+    /// this many iterations.
+    pub max_iterations: usize,
+    /// The amount of iterations GMRES takes before restarting. It is ignored
+    /// by the other solvers.
+    pub restart: usize,
+}
+
+impl<T: Real> Default for SolverOptions<T> {
+    fn default() -> Self {
+        Self {
+            tolerance: T::EPSILON.sqrt(),
+            max_iterations: 1000,
+            restart: 30,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The result of an iterative solver which converged.
+pub struct SolverReport<T: Real> {
+    /// The approximate solution `x`.
+    pub solution: Vec<T>,
+    /// The amount of iterations it took to converge.
+    pub iterations: usize,
+    /// The norm of the residual `b - A * x` of the solution, as tracked by
+    /// the solver.
+    pub residual_norm: T,
+}
+
+/// Checks that the Matrix is square and that `b` has as many elements as it
+/// has rows, returning `||b||`.
+fn check_system<'a, T: 'a + Real>(a: &impl LinearOperator<'a, T>, b: &[T]) -> Result<T> {
+    check_square(a)?;
+    if b.len() != a.get_y_len() {
+        return Err(Error::IncorrectLength {
+            expected: a.get_y_len(),
+            found: b.len(),
+        });
+    }
+
+    Ok(norm(b))
+}
+
+fn dot<T: Semiring>(lhs: &[T], rhs: &[T]) -> T {
+    lhs.iter()
+        .zip(rhs)
+        .fold(T::zero(), |sum, (l, r)| sum + *l * *r)
+}
+
+fn norm<T: Real>(x: &[T]) -> T { dot(x, x).sqrt() }
+
+/// Adds `scale * x` to `y`.
+fn axpy<T: Semiring>(scale: T, x: &[T], y: &mut [T]) {
+    for (y, x) in y.iter_mut().zip(x) {
+        *y += scale * *x;
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{
+    common::Field,
+    error::{Error, Result},
+    implementations::SparseMatrix,
+    matrix::*,
+};
+
+/// An approximation `M` of a Matrix `A` which is cheap to solve against, used
+/// to speed up the convergence of the iterative solvers.
+pub trait Preconditioner<T: Field> {
+    /// Solves `M * z = r` for `z`.
+    fn apply(&self, r: &[T]) -> Vec<T>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// The trivial Preconditioner `M = I`, which leaves the solver unchanged.
+pub struct IdentityPreconditioner;
+
+impl<T: Field> Preconditioner<T> for IdentityPreconditioner {
+    fn apply(&self, r: &[T]) -> Vec<T> { r.to_vec() }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The Jacobi Preconditioner, where `M` is the diagonal of `A`.
+pub struct JacobiPreconditioner<T: Field> {
+    inverse_diagonal: Vec<T>,
+}
+
+impl<T: Field> JacobiPreconditioner<T> {
+    /// Creates a Jacobi Preconditioner for any square Matrix, returning
+    /// `Error::NotSquare` if it is not square or `Error::Singular` if it has a
+    /// zero on its diagonal.
+    pub fn new<'a>(mat: &impl MatrixRef<'a, T>) -> Result<Self> {
+        check_square(mat)?;
+
+        let inverse_diagonal = (0..mat.get_x_len())
+            .map(|i| {
+                let cell = mat.get_at_unchecked(i, i);
+                if cell.is_zero() {
+                    Err(Error::Singular)
+                } else {
+                    Ok(T::one() / cell)
+                }
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { inverse_diagonal })
+    }
+}
+
+impl<T: Field> Preconditioner<T> for JacobiPreconditioner<T> {
+    fn apply(&self, r: &[T]) -> Vec<T> {
+        r.iter()
+            .zip(&self.inverse_diagonal)
+            .map(|(r, inverse)| *r * *inverse)
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The incomplete LU Preconditioner with no fill-in, ILU(0), where `M = L * U`
+/// is computed like an LU decomposition of `A` except that only the elements
+/// stored in `A` are kept. `L` has a unit diagonal and both factors are stored
+/// together in the sparsity pattern of `A`.
+pub struct Ilu0Preconditioner<T: Field> {
+    factors: SparseMatrix<T>,
+    diagonal: Vec<usize>,
+}
+
+impl<T: Field> Ilu0Preconditioner<T> {
+    /// Factorises a square SparseMatrix, returning `Error::NotSquare` if it is
+    /// not square or `Error::Singular` if a diagonal element is missing or
+    /// becomes zero during the factorisation.
+    pub fn new(mat: &SparseMatrix<T>) -> Result<Self> {
+        check_square(mat)?;
+
+        let n = mat.x_len;
+        let mut factors = mat.clone();
+        let offsets = &factors.row_offsets;
+        let columns = &factors.column_indices;
+        let values = &mut factors.values;
+
+        let diagonal = (0..n)
+            .map(|i| {
+                let row = offsets[i]..offsets[i + 1];
+                columns[row.clone()]
+                    .binary_search(&i)
+                    .map(|position| row.start + position)
+                    .map_err(|_| Error::Singular)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        for i in 0..n {
+            for kk in offsets[i]..diagonal[i] {
+                let k = columns[kk];
+                let pivot = values[diagonal[k]];
+                if pivot.is_zero() {
+                    return Err(Error::Singular);
+                }
+
+                let factor = values[kk] / pivot;
+                values[kk] = factor;
+
+                for jj in kk + 1..offsets[i + 1] {
+                    let row_k = diagonal[k] + 1..offsets[k + 1];
+                    if let Ok(position) =
+                        columns[row_k.clone()].binary_search(&columns[jj])
+                    {
+                        let cell = factor * values[row_k.start + position];
+                        values[jj] -= cell;
+                    }
+                }
+            }
+
+            if values[diagonal[i]].is_zero() {
+                return Err(Error::Singular);
+            }
+        }
+
+        Ok(Self { factors, diagonal })
+    }
+}
+
+impl<T: Field> Preconditioner<T> for Ilu0Preconditioner<T> {
+    fn apply(&self, r: &[T]) -> Vec<T> {
+        let offsets = &self.factors.row_offsets;
+        let columns = &self.factors.column_indices;
+        let values = &self.factors.values;
+        let mut z = r.to_vec();
+
+        for i in 0..z.len() {
+            for jj in offsets[i]..self.diagonal[i] {
+                let cell = values[jj] * z[columns[jj]];
+                z[i] -= cell;
+            }
+        }
+
+        for i in (0..z.len()).rev() {
+            for jj in self.diagonal[i] + 1..offsets[i + 1] {
+                let cell = values[jj] * z[columns[jj]];
+                z[i] -= cell;
+            }
+            z[i] /= values[self.diagonal[i]];
+        }
+
+        z
+    }
+}